@@ -0,0 +1,151 @@
+//! Conversions to and from heap-allocated types, enabled with the `alloc`
+//! feature.
+
+use crate::{ArrayVec, CapacityError, LengthType};
+use alloc::{boxed::Box, vec::Vec};
+use core::{convert::TryFrom, ptr};
+
+impl<T, const N: usize, L: LengthType> ArrayVec<T, N, L> {
+    /// Move the items into a [`Vec`] with exactly enough capacity to hold
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let vector = ArrayVec::from(["a".to_owned(), "b".to_owned()]);
+    ///
+    /// let heap = vector.into_vec();
+    ///
+    /// assert_eq!(heap, vec!["a", "b"]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        let len = self.len();
+        let mut vector = Vec::with_capacity(len);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), vector.as_mut_ptr(), len);
+            // ownership of the items has been transferred to the Vec
+            self.set_len(0);
+            vector.set_len(len);
+        }
+
+        vector
+    }
+
+    /// Move the items into a boxed slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let vector = ArrayVec::from([1, 2, 3]);
+    ///
+    /// let boxed: Box<[u32]> = vector.into_boxed_slice();
+    ///
+    /// assert_eq!(&*boxed, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into_vec().into_boxed_slice()
+    }
+
+    /// Clone the items into a new [`Vec`], leaving the vector untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let vector = ArrayVec::from(["a".to_owned(), "b".to_owned()]);
+    ///
+    /// let heap: Vec<String> = vector.to_vec();
+    ///
+    /// assert_eq!(heap, vec!["a", "b"]);
+    /// assert_eq!(vector.len(), 2);
+    /// ```
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T, const N: usize, L: LengthType> From<ArrayVec<T, N, L>> for Vec<T> {
+    #[inline]
+    fn from(vector: ArrayVec<T, N, L>) -> Vec<T> { vector.into_vec() }
+}
+
+impl<T, const N: usize, L: LengthType> From<ArrayVec<T, N, L>> for Box<[T]> {
+    #[inline]
+    fn from(vector: ArrayVec<T, N, L>) -> Box<[T]> { vector.into_boxed_slice() }
+}
+
+impl<T, const N: usize, L: LengthType> TryFrom<Vec<T>> for ArrayVec<T, N, L> {
+    type Error = CapacityError<Vec<T>>;
+
+    /// Move the items out of a [`Vec`], handing it back if they won't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// use std::convert::TryFrom;
+    ///
+    /// let vector: ArrayVec<u32, 4> = ArrayVec::try_from(vec![1, 2]).unwrap();
+    /// assert_eq!(vector.as_slice(), &[1, 2]);
+    ///
+    /// let got = ArrayVec::<u32, 1>::try_from(vec![1, 2]);
+    /// assert_eq!(got.unwrap_err(), CapacityError(vec![1, 2]));
+    /// ```
+    fn try_from(mut other: Vec<T>) -> Result<Self, Self::Error> {
+        let len = other.len();
+
+        if len > N {
+            return Err(CapacityError(other));
+        }
+
+        let mut vector = ArrayVec::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), vector.as_mut_ptr(), len);
+            // the Vec only needs to free its buffer now
+            other.set_len(0);
+            vector.set_len(len);
+        }
+
+        Ok(vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::OnDropped;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn items_are_moved_not_cloned() {
+        let counter = AtomicUsize::new(0);
+        let vector: ArrayVec<OnDropped<'_>, 4> =
+            (0..3).map(|_| OnDropped(&counter)).collect();
+
+        let heap = Vec::from(vector);
+        assert_eq!(heap.len(), 3);
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        let vector = ArrayVec::<_, 4>::try_from(heap).ok().unwrap();
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        drop(vector);
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn empty_conversions() {
+        let vector: ArrayVec<u8, 0> = ArrayVec::try_from(Vec::new()).unwrap();
+
+        assert!(vector.into_vec().is_empty());
+    }
+}
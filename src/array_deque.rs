@@ -0,0 +1,957 @@
+//! A fixed-capacity double-ended queue and its iterators.
+
+use crate::{resolve_range, ArrayVec, CapacityError, LengthType};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    mem::MaybeUninit,
+    ops::{Index, IndexMut, Range, RangeBounds},
+    ptr, slice,
+};
+
+/// A double-ended queue backed by a fixed-length array, implemented as a
+/// ring buffer.
+///
+/// Unlike [`ArrayVec`], items can be added and removed at both ends in
+/// constant time.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayDeque;
+///
+/// let mut deque: ArrayDeque<u32, 4> = ArrayDeque::new();
+///
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+///
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.len(), 1);
+/// ```
+pub struct ArrayDeque<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    /// The index of the first item in `items`.
+    head: usize,
+    length: usize,
+}
+
+impl<T, const N: usize> ArrayDeque<T, N> {
+    /// Create a new, empty [`ArrayDeque`].
+    #[inline]
+    pub const fn new() -> ArrayDeque<T, N> {
+        ArrayDeque {
+            // an array of uninitialized items is perfectly valid for
+            // [MaybeUninit<_>; N]
+            items: unsafe { MaybeUninit::uninit().assume_init() },
+            head: 0,
+            length: 0,
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize { self.length }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize { N }
+
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len() >= self.capacity() }
+
+    /// Add an item to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// The deque must have enough room for the new item (see
+    /// [`ArrayDeque::try_push_back()`] for a fallible version).
+    #[inline]
+    pub fn push_back(&mut self, item: T) {
+        if self.try_push_back(item).is_err() {
+            panic!("Push failed: Insufficient capacity");
+        }
+    }
+
+    /// Try to add an item to the back of the deque, returning the original
+    /// item if there wasn't enough room.
+    pub fn try_push_back(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError(item));
+        }
+
+        unsafe {
+            self.ptr_mut().add(self.physical(self.length)).write(item);
+        }
+        self.length += 1;
+
+        Ok(())
+    }
+
+    /// Add an item to the front of the deque.
+    ///
+    /// # Panics
+    ///
+    /// The deque must have enough room for the new item (see
+    /// [`ArrayDeque::try_push_front()`] for a fallible version).
+    #[inline]
+    pub fn push_front(&mut self, item: T) {
+        if self.try_push_front(item).is_err() {
+            panic!("Push failed: Insufficient capacity");
+        }
+    }
+
+    /// Try to add an item to the front of the deque, returning the original
+    /// item if there wasn't enough room.
+    pub fn try_push_front(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError(item));
+        }
+
+        self.head = if self.head == 0 { N - 1 } else { self.head - 1 };
+        unsafe {
+            self.ptr_mut().add(self.head).write(item);
+        }
+        self.length += 1;
+
+        Ok(())
+    }
+
+    /// Add an item to the back of the deque, removing the item at the front
+    /// to make room if the deque is full.
+    ///
+    /// The removed item is returned. With a capacity of `0` there is nothing
+    /// to remove, so `item` is handed straight back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayDeque;
+    /// let mut readings: ArrayDeque<u32, 3> = ArrayDeque::new();
+    ///
+    /// for reading in 1..=3 {
+    ///     assert_eq!(readings.push_back_overwrite(reading), None);
+    /// }
+    ///
+    /// assert_eq!(readings.push_back_overwrite(4), Some(1));
+    /// assert!(readings.iter().eq(&[2, 3, 4]));
+    /// ```
+    pub fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            return Some(item);
+        }
+
+        let oldest = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+
+        if self.try_push_back(item).is_err() {
+            unreachable!("We've made sure there is room");
+        }
+
+        oldest
+    }
+
+    /// Add an item to the front of the deque, removing the item at the back
+    /// to make room if the deque is full.
+    ///
+    /// The removed item is returned. With a capacity of `0` there is nothing
+    /// to remove, so `item` is handed straight back.
+    pub fn push_front_overwrite(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            return Some(item);
+        }
+
+        let newest = if self.is_full() {
+            self.pop_back()
+        } else {
+            None
+        };
+
+        if self.try_push_front(item).is_err() {
+            unreachable!("We've made sure there is room");
+        }
+
+        newest
+    }
+
+    /// Remove the item at the front of the deque.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let item = unsafe { self.ptr().add(self.head).read() };
+        self.head = self.physical(1);
+        self.length -= 1;
+
+        Some(item)
+    }
+
+    /// Remove the item at the back of the deque.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.length -= 1;
+        unsafe { Some(self.ptr().add(self.physical(self.length)).read()) }
+    }
+
+    /// Get a reference to the item at `index`, where `0` is the front of
+    /// the deque.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe { Some(&*self.ptr().add(self.physical(index))) }
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the item at `index`, where `0` is the
+    /// front of the deque.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            unsafe { Some(&mut *self.ptr_mut().add(self.physical(index))) }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&T> { self.get(0) }
+
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> { self.get_mut(0) }
+
+    #[inline]
+    pub fn back(&self) -> Option<&T> { self.get(self.len().wrapping_sub(1)) }
+
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().wrapping_sub(1))
+    }
+
+    /// Remove all items from the deque.
+    pub fn clear(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        let (front, back): (*mut [T], *mut [T]) = (front, back);
+
+        // Make sure a panicking destructor can't cause a double-drop
+        self.head = 0;
+        self.length = 0;
+
+        unsafe {
+            drop_both(front, back);
+        }
+    }
+
+    /// The deque's contents, in order, as a pair of slices.
+    ///
+    /// The second slice is only non-empty when the items wrap around the
+    /// end of the backing buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayDeque;
+    /// let mut deque: ArrayDeque<u32, 4> = ArrayDeque::new();
+    ///
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_ranges();
+
+        unsafe {
+            (
+                slice::from_raw_parts(self.ptr().add(front.start), front.len()),
+                slice::from_raw_parts(self.ptr().add(back.start), back.len()),
+            )
+        }
+    }
+
+    /// The deque's contents, in order, as a pair of mutable slices.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_ranges();
+        let ptr = self.ptr_mut();
+
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(front.start), front.len()),
+                slice::from_raw_parts_mut(ptr.add(back.start), back.len()),
+            )
+        }
+    }
+
+    /// Rearrange the backing buffer so every item is in a single slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayDeque;
+    /// let mut deque: ArrayDeque<u32, 4> = ArrayDeque::new();
+    ///
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2][..]));
+    ///
+    /// deque.make_contiguous().sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(deque.as_slices(), (&[2, 1][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.length > N {
+            // Note: rotating the uninitialized slots along with the items is
+            // fine because MaybeUninit<T> can be moved around freely
+            self.items.rotate_left(self.head);
+            self.head = 0;
+        }
+
+        self.as_mut_slices().0
+    }
+
+    /// Iterate over the items from front to back.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /// Iterate mutably over the items from front to back.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+
+    /// Remove the items in a range from the deque, returning an iterator
+    /// which yields them.
+    ///
+    /// Any items which haven't been yielded will be dropped when the
+    /// [`Drain`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// The range start must not be greater than the range end, and the end
+    /// must not be greater than the deque's length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayDeque;
+    /// let mut deque: ArrayDeque<u32, 8> = (1..=6).collect();
+    ///
+    /// let drained: Vec<_> = deque.drain(1..3).collect();
+    ///
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert!(deque.iter().eq(&[1, 4, 5, 6]));
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = resolve_range(&range, len);
+
+        if end > len {
+            panic!(
+                "ArrayDeque::drain(): index {} is out of bounds in deque of length {}",
+                end, len
+            );
+        }
+        if start > end {
+            panic!(
+                "ArrayDeque::drain(): range start {} is greater than range end {}",
+                start, end
+            );
+        }
+
+        // prevent a leaked Drain from letting users read items which have
+        // been moved out
+        self.length = start;
+
+        Drain {
+            deque: self,
+            drain_range_start: start,
+            head: start,
+            tail: end,
+            tail_start: end,
+            original_length: len,
+        }
+    }
+
+    #[inline]
+    fn ptr(&self) -> *const T { self.items.as_ptr() as *const T }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T { self.items.as_mut_ptr() as *mut T }
+
+    /// Convert the index of an item into its position in `items`.
+    #[inline]
+    fn physical(&self, index: usize) -> usize {
+        debug_assert!(index <= N);
+        let position = self.head + index;
+
+        if position >= N {
+            position - N
+        } else {
+            position
+        }
+    }
+
+    /// The ranges of `items` which hold the front and back halves of the
+    /// deque.
+    fn slice_ranges(&self) -> (Range<usize>, Range<usize>) {
+        let head = self.head;
+
+        if head + self.length <= N {
+            (head..head + self.length, 0..0)
+        } else {
+            (head..N, 0..head + self.length - N)
+        }
+    }
+}
+
+/// Drop the items in two slices, making sure the second is dropped even if
+/// a destructor in the first panics.
+unsafe fn drop_both<T>(front: *mut [T], back: *mut [T]) {
+    struct Dropper<T>(*mut [T]);
+
+    impl<T> Drop for Dropper<T> {
+        fn drop(&mut self) {
+            unsafe {
+                ptr::drop_in_place(self.0);
+            }
+        }
+    }
+
+    let _back = Dropper(back);
+    ptr::drop_in_place(front);
+}
+
+impl<T, const N: usize> Drop for ArrayDeque<T, N> {
+    fn drop(&mut self) { self.clear(); }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayDeque<T, N> {
+    fn clone(&self) -> ArrayDeque<T, N> { self.iter().cloned().collect() }
+}
+
+impl<T: Debug, const N: usize> Debug for ArrayDeque<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<ArrayDeque<T, M>>
+    for ArrayDeque<T, N>
+{
+    fn eq(&self, other: &ArrayDeque<T, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayDeque<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayDeque<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayDeque<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering { self.iter().cmp(other) }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayDeque<T, N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        // hash the same way a slice would, regardless of where we wrap
+        hasher.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(hasher));
+    }
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T, N> {
+    #[inline]
+    fn default() -> Self { ArrayDeque::new() }
+}
+
+impl<T, const N: usize> Index<usize> for ArrayDeque<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "ArrayDeque::index(): index {} is out of bounds in deque of length {}",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ArrayDeque<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => panic!(
+                "ArrayDeque::index_mut(): index {} is out of bounds in deque of length {}",
+                index, len
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayDeque<T, N> {
+    /// Add every item from an iterator to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// The deque must have enough space for all the items.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            if let Err(e) = self.try_push_back(item) {
+                panic!("Extend failed: {}", e);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayDeque<T, N> {
+    /// Collect the items from an iterator into an [`ArrayDeque`].
+    ///
+    /// # Panics
+    ///
+    /// The iterator must not yield more than `N` items.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ArrayDeque<T, N> {
+        let mut deque = ArrayDeque::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T, const N: usize, L: LengthType> From<ArrayVec<T, N, L>>
+    for ArrayDeque<T, N>
+{
+    fn from(vector: ArrayVec<T, N, L>) -> ArrayDeque<T, N> {
+        vector.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayDeque<T, N> {
+    fn from(array: [T; N]) -> ArrayDeque<T, N> {
+        ArrayDeque::from(ArrayVec::from(array))
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayDeque<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter { deque: self } }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayDeque<T, N> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayDeque<T, N> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+/// An iterator over references to the items in an [`ArrayDeque`].
+///
+/// This `struct` is created by [`ArrayDeque::iter()`].
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize { self.front.len() + self.back.len() }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: Debug> Debug for Iter<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
+
+/// An iterator over mutable references to the items in an [`ArrayDeque`].
+///
+/// This `struct` is created by [`ArrayDeque::iter_mut()`].
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize { self.front.len() + self.back.len() }
+}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T: Debug> Debug for IterMut<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
+
+/// An iterator which moves items out of an [`ArrayDeque`].
+///
+/// This `struct` is created by the `into_iter()` method on [`ArrayDeque`]
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Clone)]
+pub struct IntoIter<T, const N: usize> {
+    deque: ArrayDeque<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.deque.pop_front() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.deque.pop_back() }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T: Debug, const N: usize> Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.deque).finish()
+    }
+}
+
+/// A draining iterator over a range of items in an [`ArrayDeque`].
+///
+/// This `struct` is created by [`ArrayDeque::drain()`].
+pub struct Drain<'a, T, const N: usize> {
+    deque: &'a mut ArrayDeque<T, N>,
+    /// The index of the first item being removed.
+    drain_range_start: usize,
+    /// The index of the front of the remaining drained range.
+    head: usize,
+    /// One after the index of the last remaining item in the range being
+    /// drained.
+    tail: usize,
+    /// The index of the first item after the drained range.
+    tail_start: usize,
+    /// The deque's length before we started.
+    original_length: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        let index = self.deque.physical(self.head);
+        self.head += 1;
+        unsafe { Some(self.deque.ptr().add(index).read()) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tail - self.head;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        self.tail -= 1;
+        let index = self.deque.physical(self.tail);
+        unsafe { Some(self.deque.ptr().add(index).read()) }
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+impl<'a, T: Debug, const N: usize> Debug for Drain<'a, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+
+        for i in self.head..self.tail {
+            unsafe {
+                list.entry(&*self.deque.ptr().add(self.deque.physical(i)));
+            }
+        }
+
+        list.finish()
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        // drop any items which haven't been yielded yet
+        self.by_ref().for_each(drop);
+
+        let head_length = self.drain_range_start;
+        let tail_length = self.original_length - self.tail_start;
+        let drained = self.tail_start - self.drain_range_start;
+        let deque = &mut *self.deque;
+        let ptr = deque.ptr_mut();
+
+        // close the gap by moving whichever side has fewer items
+        unsafe {
+            if tail_length <= head_length {
+                for i in 0..tail_length {
+                    let src = deque.physical(self.tail_start + i);
+                    let dest = deque.physical(head_length + i);
+                    ptr::copy(ptr.add(src), ptr.add(dest), 1);
+                }
+            } else {
+                for i in (0..head_length).rev() {
+                    let src = deque.physical(i);
+                    let dest = deque.physical(i + drained);
+                    ptr::copy(ptr.add(src), ptr.add(dest), 1);
+                }
+                deque.head = deque.physical(drained);
+            }
+        }
+
+        deque.length = head_length + tail_length;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::OnDropped;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A full deque whose items wrap around the end of the buffer.
+    fn wrapped() -> ArrayDeque<u32, 5> {
+        let mut deque = ArrayDeque::new();
+        deque.extend([0, 0, 0, 1, 2]);
+        deque.pop_front();
+        deque.pop_front();
+        deque.pop_front();
+        deque.extend([3, 4, 5]);
+        deque
+    }
+
+    #[test]
+    fn push_and_pop_at_both_ends() {
+        let mut deque: ArrayDeque<u32, 3> = ArrayDeque::new();
+
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.try_push_front(0), Err(CapacityError(0)));
+        assert_eq!(deque.try_push_back(4), Err(CapacityError(4)));
+
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+    }
+
+    #[test]
+    fn overwrite_the_oldest_items() {
+        let mut deque: ArrayDeque<u32, 2> = ArrayDeque::new();
+
+        assert_eq!(deque.push_back_overwrite(1), None);
+        assert_eq!(deque.push_back_overwrite(2), None);
+        assert_eq!(deque.push_back_overwrite(3), Some(1));
+        assert_eq!(deque.push_front_overwrite(0), Some(3));
+        assert!(deque.iter().eq(&[0, 2]));
+
+        let mut empty: ArrayDeque<u32, 0> = ArrayDeque::new();
+        assert_eq!(empty.push_back_overwrite(1), Some(1));
+    }
+
+    #[test]
+    fn wrapped_items_are_visited_in_order() {
+        let mut deque = wrapped();
+
+        assert_eq!(deque.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+        assert!(deque.iter().eq(&[1, 2, 3, 4, 5]));
+        assert!(deque.iter().rev().eq(&[5, 4, 3, 2, 1]));
+        assert_eq!(deque[4], 5);
+
+        deque.iter_mut().for_each(|item| *item *= 10);
+        assert_eq!(deque.make_contiguous(), &[10, 20, 30, 40, 50]);
+        assert_eq!(deque.as_slices().1, &[]);
+
+        let items: ArrayVec<u32, 5> = deque.into_iter().rev().collect();
+        assert_eq!(items.as_slice(), &[50, 40, 30, 20, 10]);
+    }
+
+    #[test]
+    fn drain_across_the_wrap() {
+        for start in 0..=5_u32 {
+            for end in start..=5 {
+                let mut deque = wrapped();
+                let expected: ArrayVec<u32, 5> = (1..=5)
+                    .filter(|x| !(start + 1..=end).contains(x))
+                    .collect();
+
+                let drained: ArrayVec<u32, 5> =
+                    deque.drain(start as usize..end as usize).collect();
+
+                assert!(drained.iter().copied().eq(start + 1..=end));
+                assert!(deque.iter().eq(expected.iter()), "{}..{}", start, end);
+            }
+        }
+    }
+
+    #[test]
+    fn dropping_a_drain_part_way() {
+        let mut deque = wrapped();
+
+        let mut drain = deque.drain(1..4);
+        assert_eq!(drain.next(), Some(2));
+        drop(drain);
+
+        assert!(deque.iter().eq(&[1, 5]));
+        deque.push_front(0);
+        deque.push_back(6);
+        assert!(deque.iter().eq(&[0, 1, 5, 6]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn drain_past_the_end() {
+        let mut deque = wrapped();
+        deque.drain(2..6);
+    }
+
+    #[test]
+    fn every_item_is_dropped() {
+        let counter = AtomicUsize::new(0);
+        let mut deque: ArrayDeque<OnDropped<'_>, 4> = ArrayDeque::new();
+
+        for _ in 0..3 {
+            deque.push_back(OnDropped(&counter));
+        }
+        deque.pop_front();
+        deque.push_back(OnDropped(&counter));
+        deque.push_back(OnDropped(&counter));
+        assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+        drop(deque);
+        assert_eq!(counter.load(Ordering::Relaxed), 5);
+    }
+
+    #[test]
+    fn zero_sized_types() {
+        let mut deque: ArrayDeque<(), 3> = ArrayDeque::new();
+
+        deque.push_front(());
+        deque.push_back(());
+        deque.push_front(());
+
+        assert!(deque.is_full());
+        assert_eq!(deque.iter().count(), 3);
+        assert_eq!(deque.drain(1..).count(), 2);
+        assert_eq!(deque.len(), 1);
+    }
+}
@@ -0,0 +1,816 @@
+//! A fixed-capacity map and its iterators.
+
+use crate::{ArrayVec, CapacityError};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    iter::{FromIterator, FusedIterator},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+/// A map backed by an [`ArrayVec`] of key-value pairs.
+///
+/// Lookups are a linear search, which is usually faster than hashing or
+/// tree traversal for the handful of entries a fixed-capacity map holds.
+/// Entries are kept in insertion order.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayMap;
+///
+/// let mut ports: ArrayMap<&str, u16, 4> = ArrayMap::new();
+///
+/// ports.insert("http", 80);
+/// ports.insert("https", 443);
+/// ports.insert("ssh", 2222);
+/// assert_eq!(ports.insert("ssh", 22), Some(2222));
+///
+/// assert_eq!(ports.get("https"), Some(&443));
+/// assert_eq!(ports.remove("http"), Some(80));
+///
+/// let names: Vec<_> = ports.keys().copied().collect();
+/// assert_eq!(names, vec!["https", "ssh"]);
+/// ```
+#[derive(Clone)]
+pub struct ArrayMap<K, V, const N: usize> {
+    entries: ArrayVec<(K, V), N>,
+}
+
+impl<K, V, const N: usize> ArrayMap<K, V, N> {
+    /// Create a new, empty [`ArrayMap`].
+    #[inline]
+    pub const fn new() -> ArrayMap<K, V, N> {
+        ArrayMap {
+            entries: ArrayVec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize { self.entries.len() }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize { N }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.entries.is_full() }
+
+    /// The entries, in insertion order.
+    #[inline]
+    pub const fn as_slice(&self) -> &[(K, V)] { self.entries.as_slice() }
+
+    /// Remove every entry from the map.
+    #[inline]
+    pub fn clear(&mut self) { self.entries.clear(); }
+
+    /// Iterate over the entries in insertion order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over the entries in insertion order, with mutable references
+    /// to the values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.entries.iter_mut(),
+        }
+    }
+
+    /// Iterate over the keys in insertion order.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> { Keys { inner: self.iter() } }
+
+    /// Iterate over the values in insertion order.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> { Values { inner: self.iter() } }
+
+    /// Iterate mutably over the values in insertion order.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+
+    /// Only keep the entries which match a predicate, preserving their
+    /// order.
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries
+            .retain_mut(|(key, value)| predicate(key, value));
+    }
+}
+
+impl<K: Eq, V, const N: usize> ArrayMap<K, V, N> {
+    /// Insert a key-value pair, returning the previous value if the key was
+    /// already present.
+    ///
+    /// # Panics
+    ///
+    /// The map must have room for a new entry if `key` isn't already
+    /// present (see [`ArrayMap::try_insert()`] for a fallible version).
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(previous) => previous,
+            Err(e) => panic!("Insert failed: {}", e),
+        }
+    }
+
+    /// Try to insert a key-value pair, returning the previous value if the
+    /// key was already present.
+    ///
+    /// If the key is new and there's no room for it, the rejected pair is
+    /// handed back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayMap, CapacityError};
+    /// let mut map: ArrayMap<char, u32, 1> = ArrayMap::new();
+    ///
+    /// assert_eq!(map.try_insert('a', 1), Ok(None));
+    /// assert_eq!(map.try_insert('a', 2), Ok(Some(1)));
+    /// assert_eq!(map.try_insert('b', 3), Err(CapacityError(('b', 3))));
+    /// ```
+    pub fn try_insert(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => entry.try_insert(value).map(|_| None),
+        }
+    }
+
+    /// Get the entry for `key`, for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayMap;
+    /// let mut counts: ArrayMap<char, u32, 8> = ArrayMap::new();
+    ///
+    /// for letter in "hello".chars() {
+    ///     *counts.entry(letter).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts.len(), 4);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N> {
+        match self.position(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let (key, value) = &self.entries[self.position(key)?];
+        Some((key, value))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Remove an entry, returning its value.
+    ///
+    /// This shifts every later entry down to preserve the insertion order.
+    /// Use [`ArrayMap::swap_remove()`] if the order doesn't matter.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove an entry, returning its key and value.
+    ///
+    /// This shifts every later entry down to preserve the insertion order.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// Remove an entry by replacing it with the last one, returning its
+    /// value.
+    ///
+    /// This doesn't preserve the insertion order, but it doesn't need to
+    /// shift any other entries around either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayMap;
+    /// let mut map: ArrayMap<u32, char, 4> = (1..=4).zip('a'..).collect();
+    ///
+    /// assert_eq!(map.swap_remove(&1), Some('a'));
+    ///
+    /// let keys: Vec<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, vec![4, 2, 3]);
+    /// ```
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(self.entries.swap_remove(index).1)
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+}
+
+impl<K: Debug, V: Debug, const N: usize> Debug for ArrayMap<K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N: usize> Default for ArrayMap<K, V, N> {
+    #[inline]
+    fn default() -> Self { ArrayMap::new() }
+}
+
+impl<K, V, const N: usize, const M: usize> PartialEq<ArrayMap<K, V, M>>
+    for ArrayMap<K, V, N>
+where
+    K: Eq,
+    V: PartialEq,
+{
+    /// Two maps are equal if they contain the same entries, regardless of
+    /// order.
+    fn eq(&self, other: &ArrayMap<K, V, M>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for ArrayMap<K, V, N> {}
+
+impl<K, V, Q, const N: usize> Index<&Q> for ArrayMap<K, V, N>
+where
+    K: Eq + Borrow<Q>,
+    Q: Eq + ?Sized,
+{
+    type Output = V;
+
+    /// Get the value for a key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key isn't present.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("ArrayMap::index(): key not found")
+    }
+}
+
+impl<K, V, Q, const N: usize> IndexMut<&Q> for ArrayMap<K, V, N>
+where
+    K: Eq + Borrow<Q>,
+    Q: Eq + ?Sized,
+{
+    fn index_mut(&mut self, key: &Q) -> &mut V {
+        self.get_mut(key)
+            .expect("ArrayMap::index_mut(): key not found")
+    }
+}
+
+impl<K: Eq, V, const N: usize> Extend<(K, V)> for ArrayMap<K, V, N> {
+    /// Insert every key-value pair from an iterator, overwriting the values
+    /// of existing keys.
+    ///
+    /// # Panics
+    ///
+    /// The map must have room for all the new keys.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq, V, const N: usize> FromIterator<(K, V)> for ArrayMap<K, V, N> {
+    /// Collect key-value pairs into an [`ArrayMap`]. Later values overwrite
+    /// earlier ones with the same key.
+    ///
+    /// # Panics
+    ///
+    /// The iterator must not yield more than `N` distinct keys.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = ArrayMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ArrayMap<K, V, N> {
+    type IntoIter = IntoIter<K, V, N>;
+    type Item = (K, V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a ArrayMap<K, V, N> {
+    type IntoIter = Iter<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a mut ArrayMap<K, V, N> {
+    type IntoIter = IterMut<'a, K, V>;
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+/// A view into a single entry in an [`ArrayMap`].
+///
+/// This `enum` is created by [`ArrayMap::entry()`].
+pub enum Entry<'a, K, V, const N: usize> {
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    Vacant(VacantEntry<'a, K, V, N>),
+}
+
+impl<'a, K, V, const N: usize> Entry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Get the value, inserting `default` if the key isn't present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key needs to be inserted and the map is full.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Get the value, inserting the result of `default()` if the key isn't
+    /// present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key needs to be inserted and the map is full.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Get the value, inserting [`Default::default()`] if the key isn't
+    /// present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key needs to be inserted and the map is full.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Update the value in-place if the key is present.
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K: Debug, V: Debug, const N: usize> Debug for Entry<'a, K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Occupied(entry) => entry.fmt(f),
+            Entry::Vacant(entry) => entry.fmt(f),
+        }
+    }
+}
+
+/// An entry in an [`ArrayMap`] whose key is present.
+pub struct OccupiedEntry<'a, K, V, const N: usize> {
+    map: &'a mut ArrayMap<K, V, N>,
+    index: usize,
+}
+
+impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K { &self.map.entries[self.index].0 }
+
+    #[inline]
+    pub fn get(&self) -> &V { &self.map.entries[self.index].1 }
+
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V { &mut self.map.entries[self.index].1 }
+
+    /// Convert into a mutable reference to the value, tied to the map's
+    /// lifetime.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V { &mut self.map.entries[self.index].1 }
+
+    /// Replace the value, returning the old one.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove the entry, preserving the order of the others.
+    #[inline]
+    pub fn remove(self) -> V { self.remove_entry().1 }
+
+    /// Remove the entry, preserving the order of the others.
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) { self.map.entries.remove(self.index) }
+}
+
+impl<'a, K: Debug, V: Debug, const N: usize> Debug
+    for OccupiedEntry<'a, K, V, N>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// An entry in an [`ArrayMap`] whose key isn't present.
+pub struct VacantEntry<'a, K, V, const N: usize> {
+    map: &'a mut ArrayMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize> VacantEntry<'a, K, V, N> {
+    #[inline]
+    pub fn key(&self) -> &K { &self.key }
+
+    #[inline]
+    pub fn into_key(self) -> K { self.key }
+
+    /// Insert the value, returning a mutable reference to it.
+    ///
+    /// # Panics
+    ///
+    /// The map must have room for a new entry (see
+    /// [`VacantEntry::try_insert()`] for a fallible version).
+    pub fn insert(self, value: V) -> &'a mut V {
+        match self.try_insert(value) {
+            Ok(value) => value,
+            Err(e) => panic!("Insert failed: {}", e),
+        }
+    }
+
+    /// Try to insert the value, handing back the key-value pair if the map
+    /// is full.
+    pub fn try_insert(
+        self,
+        value: V,
+    ) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        let entries = &mut self.map.entries;
+        entries.try_push((self.key, value))?;
+
+        let last = entries.len() - 1;
+        Ok(&mut entries[last].1)
+    }
+}
+
+impl<'a, K: Debug, V, const N: usize> Debug for VacantEntry<'a, K, V, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+/// An iterator over the entries in an [`ArrayMap`].
+///
+/// This `struct` is created by [`ArrayMap::iter()`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Iter<'a, K, V> {}
+
+/// An iterator over the entries in an [`ArrayMap`], with mutable references
+/// to the values.
+///
+/// This `struct` is created by [`ArrayMap::iter_mut()`].
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (&*key, value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, value)| (&*key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for IterMut<'a, K, V> {}
+
+/// An iterator over the keys in an [`ArrayMap`].
+///
+/// This `struct` is created by [`ArrayMap::keys()`].
+#[derive(Debug, Clone)]
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values in an [`ArrayMap`].
+///
+/// This `struct` is created by [`ArrayMap::values()`].
+#[derive(Debug, Clone)]
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for Values<'a, K, V> {}
+
+/// An iterator over mutable references to the values in an [`ArrayMap`].
+///
+/// This `struct` is created by [`ArrayMap::values_mut()`].
+#[derive(Debug)]
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K, V> FusedIterator for ValuesMut<'a, K, V> {}
+
+/// An iterator which moves the entries out of an [`ArrayMap`].
+///
+/// This `struct` is created by the `into_iter()` method on [`ArrayMap`]
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Debug, Clone)]
+pub struct IntoIter<K, V, const N: usize> {
+    inner: crate::IntoIter<(K, V), N>,
+}
+
+impl<K, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<K, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back() }
+}
+
+impl<K, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {}
+
+impl<K, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_stay_in_insertion_order() {
+        let mut map: ArrayMap<u32, &str, 4> = ArrayMap::new();
+
+        map.insert(3, "three");
+        map.insert(1, "one");
+        map.insert(2, "two");
+        map.insert(1, "uno");
+        map.remove(&3);
+        map.insert(4, "four");
+
+        assert_eq!(map.as_slice(), &[(1, "uno"), (2, "two"), (4, "four")]);
+        assert!(map.values().rev().eq(&["four", "two", "uno"]));
+    }
+
+    #[test]
+    fn full_maps_reject_new_keys() {
+        let mut map: ArrayMap<u32, u32, 2> = (0..2).map(|i| (i, i)).collect();
+
+        assert_eq!(map.try_insert(1, 10), Ok(Some(1)));
+        assert_eq!(map.try_insert(2, 20), Err(CapacityError((2, 20))));
+
+        match map.entry(5) {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.try_insert(50), Err(CapacityError((5, 50))));
+            },
+            Entry::Occupied(_) => unreachable!(),
+        }
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insert failed: Insufficient capacity")]
+    fn insert_into_a_full_map() {
+        let mut map: ArrayMap<u32, u32, 1> = ArrayMap::new();
+
+        map.insert(1, 1);
+        map.insert(2, 2);
+    }
+
+    #[test]
+    fn entry_api() {
+        let mut map: ArrayMap<&str, u32, 4> = ArrayMap::new();
+
+        map.entry("a").or_default();
+        map.entry("a").and_modify(|v| *v += 10).or_insert(42);
+        map.entry("b").and_modify(|v| *v += 10).or_insert(42);
+
+        assert_eq!(map.get("a"), Some(&10));
+        assert_eq!(map.get("b"), Some(&42));
+
+        if let Entry::Occupied(entry) = map.entry("a") {
+            assert_eq!(entry.remove_entry(), ("a", 10));
+        }
+        assert!(!map.contains_key("a"));
+    }
+
+    #[test]
+    fn equality_ignores_order() {
+        let first: ArrayMap<u32, char, 3> =
+            [(1, 'a'), (2, 'b')].iter().copied().collect();
+        let second: ArrayMap<u32, char, 5> =
+            [(2, 'b'), (1, 'a')].iter().copied().collect();
+        let third: ArrayMap<u32, char, 3> =
+            [(2, 'c'), (1, 'a')].iter().copied().collect();
+
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+    }
+}
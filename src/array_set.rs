@@ -0,0 +1,414 @@
+//! A fixed-capacity set backed by an [`ArrayVec`], with the usual set
+//! operations.
+
+use crate::{ArrayVec, CapacityError, IntoIter};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    iter::FromIterator,
+    slice,
+};
+
+/// A set backed by an [`ArrayVec`].
+///
+/// Like [`ArrayMap`](crate::ArrayMap), lookups are a linear search and
+/// items are kept in insertion order.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArraySet;
+///
+/// let mut primes: ArraySet<u32, 4> = ArraySet::new();
+///
+/// assert!(primes.insert(2));
+/// assert!(primes.insert(3));
+/// assert!(!primes.insert(2));
+///
+/// assert!(primes.contains(&3));
+/// assert_eq!(primes.len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct ArraySet<T, const N: usize> {
+    items: ArrayVec<T, N>,
+}
+
+impl<T, const N: usize> ArraySet<T, N> {
+    /// Create a new, empty [`ArraySet`].
+    #[inline]
+    pub const fn new() -> ArraySet<T, N> {
+        ArraySet {
+            items: ArrayVec::new(),
+        }
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize { self.items.len() }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+    #[inline]
+    pub const fn capacity(&self) -> usize { N }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.items.is_full() }
+
+    /// The items, in insertion order.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] { self.items.as_slice() }
+
+    /// Iterate over the items in insertion order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> { self.items.iter() }
+
+    /// Remove every item from the set.
+    #[inline]
+    pub fn clear(&mut self) { self.items.clear(); }
+
+    /// Only keep the items which match a predicate, preserving their order.
+    #[inline]
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.items.retain(predicate);
+    }
+}
+
+impl<T: Eq, const N: usize> ArraySet<T, N> {
+    /// Add an item to the set, returning `false` if it was already present.
+    ///
+    /// # Panics
+    ///
+    /// The set must have room for the item if it isn't already present
+    /// (see [`ArraySet::try_insert()`] for a fallible version).
+    pub fn insert(&mut self, item: T) -> bool {
+        match self.try_insert(item) {
+            Ok(inserted) => inserted,
+            Err(e) => panic!("Insert failed: {}", e),
+        }
+    }
+
+    /// Try to add an item to the set, returning `false` if it was already
+    /// present.
+    ///
+    /// If the item is new and there's no room for it, it is handed back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArraySet, CapacityError};
+    /// let mut set: ArraySet<char, 1> = ArraySet::new();
+    ///
+    /// assert_eq!(set.try_insert('a'), Ok(true));
+    /// assert_eq!(set.try_insert('a'), Ok(false));
+    /// assert_eq!(set.try_insert('b'), Err(CapacityError('b')));
+    /// ```
+    pub fn try_insert(&mut self, item: T) -> Result<bool, CapacityError<T>> {
+        if self.contains(&item) {
+            return Ok(false);
+        }
+
+        self.items.try_push(item)?;
+        Ok(true)
+    }
+
+    #[inline]
+    pub fn contains<Q>(&self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(item).is_some()
+    }
+
+    /// Get a reference to the item in the set which is equal to `item`.
+    pub fn get<Q>(&self, item: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(item).map(|index| &self.items[index])
+    }
+
+    /// Remove an item, returning `true` if it was present.
+    ///
+    /// This shifts every later item down to preserve the insertion order.
+    #[inline]
+    pub fn remove<Q>(&mut self, item: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.take(item).is_some()
+    }
+
+    /// Remove and return the item in the set which is equal to `item`.
+    pub fn take<Q>(&mut self, item: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(item)?;
+        Some(self.items.remove(index))
+    }
+
+    /// Does every item in this set also appear in `other`?
+    pub fn is_subset<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        self.len() <= other.len()
+            && self.iter().all(|item| other.contains(item))
+    }
+
+    /// Does every item in `other` also appear in this set?
+    #[inline]
+    pub fn is_superset<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Do the two sets have no items in common?
+    pub fn is_disjoint<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        self.iter().all(|item| !other.contains(item))
+    }
+
+    /// Create a set with every item in either set, with room for `R` items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArraySet, CapacityError};
+    ///
+    /// let a: ArraySet<u32, 3> = [1, 2, 3].iter().copied().collect();
+    /// let b: ArraySet<u32, 3> = [3, 4, 5].iter().copied().collect();
+    ///
+    /// let union: ArraySet<u32, 6> = a.union(&b).unwrap();
+    /// assert_eq!(union.as_slice(), &[1, 2, 3, 4, 5]);
+    ///
+    /// let too_small = a.union::<3, 4>(&b);
+    /// assert_eq!(too_small.unwrap_err(), CapacityError(()));
+    /// ```
+    pub fn union<const M: usize, const R: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, R>, CapacityError<()>>
+    where
+        T: Clone,
+    {
+        let mut set = ArraySet::new();
+
+        for item in self.iter().chain(other) {
+            set.try_insert(item.clone())
+                .map_err(|_| CapacityError(()))?;
+        }
+
+        Ok(set)
+    }
+
+    /// Create a set with the items which appear in both sets.
+    ///
+    /// This can never have more items than `self`, so it can't fail.
+    pub fn intersection<const M: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> ArraySet<T, N>
+    where
+        T: Clone,
+    {
+        self.filtered(|item| other.contains(item))
+    }
+
+    /// Create a set with the items in `self` which don't appear in `other`.
+    ///
+    /// This can never have more items than `self`, so it can't fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArraySet;
+    ///
+    /// let a: ArraySet<u32, 3> = [1, 2, 3].iter().copied().collect();
+    /// let b: ArraySet<u32, 3> = [3, 4, 5].iter().copied().collect();
+    ///
+    /// assert_eq!(a.intersection(&b).as_slice(), &[3]);
+    /// assert_eq!(a.difference(&b).as_slice(), &[1, 2]);
+    /// ```
+    pub fn difference<const M: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> ArraySet<T, N>
+    where
+        T: Clone,
+    {
+        self.filtered(|item| !other.contains(item))
+    }
+
+    /// Create a set with the items which appear in exactly one of the sets,
+    /// with room for `R` items.
+    pub fn symmetric_difference<const M: usize, const R: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, R>, CapacityError<()>>
+    where
+        T: Clone,
+    {
+        let mut set = ArraySet::new();
+
+        let left = self.iter().filter(|item| !other.contains(*item));
+        let right = other.iter().filter(|item| !self.contains(*item));
+
+        for item in left.chain(right) {
+            // Note: the items are already unique
+            set.items
+                .try_push(item.clone())
+                .map_err(|_| CapacityError(()))?;
+        }
+
+        Ok(set)
+    }
+
+    fn filtered<F>(&self, mut predicate: F) -> ArraySet<T, N>
+    where
+        F: FnMut(&T) -> bool,
+        T: Clone,
+    {
+        let mut set = ArraySet::new();
+
+        for item in self.iter().filter(|item| predicate(item)) {
+            unsafe {
+                // a subset of our items will always fit
+                set.items.push_unchecked(item.clone());
+            }
+        }
+
+        set
+    }
+
+    fn position<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.items.iter().position(|i| i.borrow() == item)
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ArraySet<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for ArraySet<T, N> {
+    #[inline]
+    fn default() -> Self { ArraySet::new() }
+}
+
+impl<T: Eq, const N: usize, const M: usize> PartialEq<ArraySet<T, M>>
+    for ArraySet<T, N>
+{
+    /// Two sets are equal if they contain the same items, regardless of
+    /// order.
+    fn eq(&self, other: &ArraySet<T, M>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArraySet<T, N> {}
+
+impl<T: Eq, const N: usize> Extend<T> for ArraySet<T, N> {
+    /// Add every item from an iterator, skipping duplicates.
+    ///
+    /// # Panics
+    ///
+    /// The set must have room for all the new items.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: Eq, const N: usize> FromIterator<T> for ArraySet<T, N> {
+    /// Collect the unique items from an iterator into an [`ArraySet`].
+    ///
+    /// # Panics
+    ///
+    /// The iterator must not yield more than `N` unique items.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ArraySet<T, N> {
+        let mut set = ArraySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArraySet<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.items.into_iter() }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArraySet<T, N> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set<const N: usize>(items: &[u32]) -> ArraySet<u32, N> {
+        items.iter().copied().collect()
+    }
+
+    #[test]
+    fn duplicates_are_rejected() {
+        let mut set: ArraySet<u32, 2> = set(&[1, 2, 1, 2]);
+
+        assert_eq!(set.as_slice(), &[1, 2]);
+        assert_eq!(set.try_insert(1), Ok(false));
+        assert_eq!(set.try_insert(3), Err(CapacityError(3)));
+
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(set.take(&2), Some(2));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a: ArraySet<u32, 4> = set(&[1, 2, 3, 4]);
+        let b: ArraySet<u32, 3> = set(&[3, 4, 5]);
+
+        let union: ArraySet<u32, 5> = a.union(&b).unwrap();
+        assert_eq!(union, set::<5>(&[5, 4, 3, 2, 1]));
+
+        assert_eq!(a.intersection(&b), set::<2>(&[3, 4]));
+        assert_eq!(a.difference(&b), set::<2>(&[1, 2]));
+        assert_eq!(b.difference(&a), set::<1>(&[5]));
+
+        let symmetric: ArraySet<u32, 3> = a.symmetric_difference(&b).unwrap();
+        assert_eq!(symmetric.as_slice(), &[1, 2, 5]);
+        assert_eq!(
+            a.symmetric_difference::<3, 2>(&b).unwrap_err(),
+            CapacityError(())
+        );
+    }
+
+    #[test]
+    fn subsets_and_supersets() {
+        let small: ArraySet<u32, 2> = set(&[2, 1]);
+        let big: ArraySet<u32, 4> = set(&[1, 2, 3]);
+        let other: ArraySet<u32, 2> = set(&[7, 8]);
+
+        assert!(small.is_subset(&big));
+        assert!(big.is_superset(&small));
+        assert!(!big.is_subset(&small));
+        assert!(small.is_disjoint(&other));
+        assert!(!small.is_disjoint(&big));
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{ArrayVec, CapacityError};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter, Write},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    ptr,
+    str::{self, FromStr},
+};
+
+/// A UTF-8 string backed by a fixed-length array of bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayString;
+/// use core::fmt::Write;
+///
+/// let mut line: ArrayString<32> = ArrayString::new();
+/// write!(line, "{}: {}", "temperature", 42).unwrap();
+///
+/// assert_eq!(line, "temperature: 42");
+/// assert_eq!(line.remaining_capacity(), 17);
+/// ```
+#[derive(Clone, Default)]
+pub struct ArrayString<const N: usize> {
+    /// The string's bytes. Must always contain valid UTF-8.
+    vector: ArrayVec<u8, N>,
+}
+
+impl<const N: usize> ArrayString<N> {
+    /// Create a new, empty [`ArrayString`].
+    #[inline]
+    pub fn new() -> ArrayString<N> {
+        ArrayString {
+            vector: ArrayVec::new(),
+        }
+    }
+
+    /// The string's length, in bytes.
+    #[inline]
+    pub fn len(&self) -> usize { self.vector.len() }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.vector.is_empty() }
+
+    /// The maximum number of bytes this string can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize { N }
+
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        self.vector.remaining_capacity()
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool { self.vector.is_full() }
+
+    #[inline]
+    pub fn as_str(&self) -> &str { self.deref() }
+
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str { self.deref_mut() }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { self.vector.as_slice() }
+
+    /// Add a character to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// The string must have enough space for the character's UTF-8
+    /// encoding (see [`ArrayString::try_push()`]).
+    pub fn push(&mut self, c: char) {
+        match self.try_push(c) {
+            Ok(_) => {},
+            Err(e) => panic!("Push failed: {}", e),
+        }
+    }
+
+    /// Try to add a character to the end of the string, returning the
+    /// original character if there wasn't enough room.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayString, CapacityError};
+    /// let mut s: ArrayString<3> = ArrayString::new();
+    ///
+    /// assert!(s.try_push('a').is_ok());
+    /// assert_eq!(s.try_push('ß'), Ok(()));
+    ///
+    /// // '€' needs 3 bytes, but the string is already full
+    /// assert_eq!(s.try_push('€'), Err(CapacityError('€')));
+    /// assert_eq!(s, "aß");
+    /// ```
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        let mut buffer = [0; 4];
+        let encoded = c.encode_utf8(&mut buffer);
+
+        self.vector
+            .try_extend_from_slice(encoded.as_bytes())
+            .map_err(|_| CapacityError(c))
+    }
+
+    /// Add a string slice to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// The string must have enough space for the entire slice (see
+    /// [`ArrayString::try_push_str()`]).
+    pub fn push_str(&mut self, s: &str) {
+        match self.try_push_str(s) {
+            Ok(_) => {},
+            Err(e) => panic!("Push failed: {}", e),
+        }
+    }
+
+    /// Try to add a string slice to the end of the string, returning the
+    /// original slice if there wasn't enough room.
+    ///
+    /// The string is left unchanged on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayString, CapacityError};
+    /// let mut s: ArrayString<8> = ArrayString::new();
+    ///
+    /// assert!(s.try_push_str("Hello").is_ok());
+    /// assert_eq!(s.try_push_str(", World"), Err(CapacityError(", World")));
+    /// assert_eq!(s, "Hello");
+    /// ```
+    pub fn try_push_str<'a>(
+        &mut self,
+        s: &'a str,
+    ) -> Result<(), CapacityError<&'a str>> {
+        self.vector
+            .try_extend_from_slice(s.as_bytes())
+            .map_err(|_| CapacityError(s))
+    }
+
+    /// Remove the last character from the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayString;
+    /// let mut s: ArrayString<8> = "añb".parse().unwrap();
+    ///
+    /// assert_eq!(s.pop(), Some('b'));
+    /// assert_eq!(s.pop(), Some('ñ'));
+    /// assert_eq!(s.pop(), Some('a'));
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.chars().next_back()?;
+        let new_length = self.len() - c.len_utf8();
+        self.vector.truncate(new_length);
+
+        Some(c)
+    }
+
+    /// Shorten the string to `new_length` bytes.
+    ///
+    /// This has no effect if `new_length` is greater than the string's
+    /// current length.
+    ///
+    /// # Panics
+    ///
+    /// `new_length` must lie on a `char` boundary.
+    pub fn truncate(&mut self, new_length: usize) {
+        if new_length < self.len() {
+            assert!(
+                self.is_char_boundary(new_length),
+                "ArrayString::truncate(): index {} is not a char boundary",
+                new_length
+            );
+            self.vector.truncate(new_length);
+        }
+    }
+
+    /// Remove all text from the string.
+    #[inline]
+    pub fn clear(&mut self) { self.vector.clear(); }
+
+    /// Insert a character at a byte index.
+    ///
+    /// # Panics
+    ///
+    /// The index must lie on a `char` boundary and the string must have
+    /// enough space for the character (see [`ArrayString::try_insert()`]).
+    pub fn insert(&mut self, index: usize, c: char) {
+        match self.try_insert(index, c) {
+            Ok(_) => {},
+            Err(e) => panic!("Insert failed: {}", e),
+        }
+    }
+
+    /// Try to insert a character at a byte index, returning the original
+    /// character if there wasn't enough room.
+    ///
+    /// # Panics
+    ///
+    /// The index must lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayString, CapacityError};
+    /// let mut s: ArrayString<4> = "ac".parse().unwrap();
+    ///
+    /// s.try_insert(1, 'b').unwrap();
+    /// assert_eq!(s, "abc");
+    ///
+    /// assert_eq!(s.try_insert(0, 'ñ'), Err(CapacityError('ñ')));
+    /// ```
+    pub fn try_insert(
+        &mut self,
+        index: usize,
+        c: char,
+    ) -> Result<(), CapacityError<char>> {
+        assert!(
+            self.is_char_boundary(index),
+            "ArrayString::try_insert(): index {} is not a char boundary",
+            index
+        );
+
+        let mut buffer = [0; 4];
+        let encoded = c.encode_utf8(&mut buffer).as_bytes();
+
+        if self.remaining_capacity() < encoded.len() {
+            return Err(CapacityError(c));
+        }
+
+        let len = self.len();
+
+        unsafe {
+            let ptr_index = self.vector.as_mut_ptr().add(index);
+            // Shift everything after the index over to make space, then
+            // write the encoded character into the gap.
+            ptr::copy(ptr_index, ptr_index.add(encoded.len()), len - index);
+            ptr::copy_nonoverlapping(
+                encoded.as_ptr(),
+                ptr_index,
+                encoded.len(),
+            );
+            self.vector.set_len(len + encoded.len());
+        }
+
+        Ok(())
+    }
+
+    /// Remove the character starting at a byte index and return it.
+    ///
+    /// # Panics
+    ///
+    /// The index must lie on a `char` boundary and be less than the string's
+    /// length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayString;
+    /// let mut s: ArrayString<8> = "añb".parse().unwrap();
+    ///
+    /// assert_eq!(s.remove(1), 'ñ');
+    /// assert_eq!(s, "ab");
+    /// ```
+    pub fn remove(&mut self, index: usize) -> char {
+        let c = match self.get(index..).and_then(|rest| rest.chars().next()) {
+            Some(c) => c,
+            None => panic!(
+                "ArrayString::remove(): index {} is out of bounds or not a \
+                 char boundary in string of length {}",
+                index,
+                self.len()
+            ),
+        };
+
+        let len = self.len();
+        let char_length = c.len_utf8();
+
+        unsafe {
+            let ptr_index = self.vector.as_mut_ptr().add(index);
+            // Shift everything after the character to the left.
+            ptr::copy(
+                ptr_index.add(char_length),
+                ptr_index,
+                len - index - char_length,
+            );
+            self.vector.set_len(len - char_length);
+        }
+
+        c
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        // Safety: we only ever add valid UTF-8 to the vector
+        unsafe { str::from_utf8_unchecked(self.vector.as_slice()) }
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayString<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        // Safety: we only ever add valid UTF-8 to the vector
+        unsafe { str::from_utf8_unchecked_mut(self.vector.as_slice_mut()) }
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    #[inline]
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl<const N: usize> AsMut<str> for ArrayString<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut str { self.as_mut_str() }
+}
+
+impl<const N: usize> AsRef<[u8]> for ArrayString<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+
+impl<const N: usize> Borrow<str> for ArrayString<N> {
+    #[inline]
+    fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl<const N: usize> BorrowMut<str> for ArrayString<N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str { self.as_mut_str() }
+}
+
+impl<const N: usize> Write for ArrayString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> FromStr for ArrayString<N> {
+    type Err = CapacityError<()>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut string = ArrayString::new();
+        string.try_push_str(s).map_err(|_| CapacityError(()))?;
+        Ok(string)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<&'a str> for ArrayString<N> {
+    type Error = CapacityError<&'a str>;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut string = ArrayString::new();
+        string.try_push_str(s)?;
+        Ok(string)
+    }
+}
+
+impl<const N: usize> Display for ArrayString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Debug for ArrayString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ArrayString<M>>
+    for ArrayString<N>
+{
+    #[inline]
+    fn eq(&self, other: &ArrayString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for ArrayString<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+
+impl<'a, const N: usize> PartialEq<&'a str> for ArrayString<N> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool { self.as_str() == *other }
+}
+
+impl<const N: usize> PartialEq<ArrayString<N>> for str {
+    #[inline]
+    fn eq(&self, other: &ArrayString<N>) -> bool { self == other.as_str() }
+}
+
+impl<const N: usize> PartialEq<ArrayString<N>> for &str {
+    #[inline]
+    fn eq(&self, other: &ArrayString<N>) -> bool { *self == other.as_str() }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> PartialOrd for ArrayString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> PartialOrd<str> for ArrayString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl<const N: usize> Ord for ArrayString<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for ArrayString<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) { self.as_str().hash(hasher); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_remove_multi_byte_characters() {
+        let mut s: ArrayString<16> = "hello".parse().unwrap();
+
+        s.insert(0, '¡');
+        s.insert(s.len(), '€');
+        assert_eq!(s, "¡hello€");
+
+        assert_eq!(s.remove(0), '¡');
+        assert_eq!(s.remove(5), '€');
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "not a char boundary")]
+    fn truncate_in_the_middle_of_a_character() {
+        let mut s: ArrayString<8> = "ñ".parse().unwrap();
+
+        s.truncate(1);
+    }
+
+    #[test]
+    fn write_fails_when_full() {
+        let mut s: ArrayString<4> = ArrayString::new();
+
+        assert!(write!(s, "{}", 12345).is_err());
+        assert!(s.len() <= s.capacity());
+        assert!(write!(s, "").is_ok());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{length::AssertLengthFits, ArrayVec, IntoIter, LengthType};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+/// A [`Copy`]-able vector type backed by a fixed-length array.
+///
+/// [`ArrayVec`] always has a destructor, which stops it from being [`Copy`]
+/// even when its items are. A [`CopyArrayVec`] has the same layout but no
+/// destructor, so it can only hold [`Copy`] items.
+///
+/// It dereferences to an [`ArrayVec`], so the entire [`ArrayVec`] API is
+/// available.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::CopyArrayVec;
+///
+/// #[derive(Debug, Copy, Clone, PartialEq)]
+/// struct Packet {
+///     id: u8,
+///     payload: CopyArrayVec<u8, 15>,
+/// }
+///
+/// let mut payload = CopyArrayVec::new();
+/// payload.push(0xDE);
+/// payload.push(0xAD);
+/// let original = Packet { id: 1, payload };
+///
+/// let mut copy = original;
+/// copy.payload.retain(|&b| b != 0xAD);
+///
+/// assert_eq!(original.payload.as_slice(), &[0xDE, 0xAD]);
+/// assert_eq!(copy.payload.as_slice(), &[0xDE]);
+/// ```
+// Note: this must have exactly the same layout as ArrayVec
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CopyArrayVec<T: Copy, const N: usize, L: LengthType = usize> {
+    items: [MaybeUninit<T>; N],
+    length: L,
+}
+
+impl<T: Copy, const N: usize, L: LengthType> CopyArrayVec<T, N, L> {
+    /// Create a new, empty [`CopyArrayVec`].
+    #[inline]
+    pub const fn new() -> CopyArrayVec<T, N, L> {
+        let () = AssertLengthFits::<L, N>::OK;
+
+        CopyArrayVec {
+            // an array of uninitialized items is perfectly valid for
+            // [MaybeUninit<_>; N]
+            items: unsafe { MaybeUninit::uninit().assume_init() },
+            length: L::ZERO,
+        }
+    }
+
+    /// Get a reference to the equivalent [`ArrayVec`].
+    #[inline]
+    pub const fn as_array_vec(&self) -> &ArrayVec<T, N, L> {
+        // Safety: both types are #[repr(C)] with the same fields
+        unsafe {
+            &*(self as *const CopyArrayVec<T, N, L> as *const ArrayVec<T, N, L>)
+        }
+    }
+
+    /// Get a mutable reference to the equivalent [`ArrayVec`].
+    #[inline]
+    pub const fn as_array_vec_mut(&mut self) -> &mut ArrayVec<T, N, L> {
+        // Safety: both types are #[repr(C)] with the same fields, and because
+        // the items are Copy it doesn't matter that ArrayVec's destructor
+        // will never run
+        unsafe {
+            &mut *(self as *mut CopyArrayVec<T, N, L> as *mut ArrayVec<T, N, L>)
+        }
+    }
+
+    /// Convert into an [`ArrayVec`].
+    #[inline]
+    pub const fn into_array_vec(self) -> ArrayVec<T, N, L> {
+        ArrayVec {
+            items: self.items,
+            length: self.length,
+        }
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> Deref for CopyArrayVec<T, N, L> {
+    type Target = ArrayVec<T, N, L>;
+
+    #[inline]
+    fn deref(&self) -> &ArrayVec<T, N, L> { self.as_array_vec() }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> DerefMut
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut ArrayVec<T, N, L> {
+        self.as_array_vec_mut()
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> From<ArrayVec<T, N, L>>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn from(vector: ArrayVec<T, N, L>) -> CopyArrayVec<T, N, L> {
+        CopyArrayVec {
+            items: vector.items,
+            length: vector.length,
+        }
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> From<CopyArrayVec<T, N, L>>
+    for ArrayVec<T, N, L>
+{
+    #[inline]
+    fn from(vector: CopyArrayVec<T, N, L>) -> ArrayVec<T, N, L> {
+        vector.into_array_vec()
+    }
+}
+
+impl<T: Copy, const N: usize> From<[T; N]> for CopyArrayVec<T, N> {
+    #[inline]
+    fn from(array: [T; N]) -> CopyArrayVec<T, N> {
+        CopyArrayVec::from(ArrayVec::from(array))
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> Default for CopyArrayVec<T, N, L> {
+    #[inline]
+    fn default() -> Self { CopyArrayVec::new() }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> AsRef<[T]>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> AsMut<[T]>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+
+impl<T: Copy + Debug, const N: usize, L: LengthType> Debug
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, const N: usize, const M: usize, L, L2> PartialEq<CopyArrayVec<T, M, L2>>
+    for CopyArrayVec<T, N, L>
+where
+    T: Copy + PartialEq,
+    L: LengthType,
+    L2: LengthType,
+{
+    #[inline]
+    fn eq(&self, other: &CopyArrayVec<T, M, L2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize, L: LengthType> PartialEq<[T]>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: Copy + Eq, const N: usize, L: LengthType> Eq for CopyArrayVec<T, N, L> {}
+
+impl<T: Copy + PartialOrd, const N: usize, L: LengthType> PartialOrd
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Copy + Ord, const N: usize, L: LengthType> Ord
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Copy + Hash, const N: usize, L: LengthType> Hash
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) { self.as_slice().hash(hasher); }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> Extend<T>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.as_array_vec_mut().extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize, L: LengthType> Extend<&'a T>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.as_array_vec_mut().extend(iter);
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> FromIterator<T>
+    for CopyArrayVec<T, N, L>
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        CopyArrayVec::from(ArrayVec::from_iter(iter))
+    }
+}
+
+impl<T: Copy, const N: usize, L: LengthType> IntoIterator
+    for CopyArrayVec<T, N, L>
+{
+    type IntoIter = IntoIter<T, N, L>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.into_array_vec().into_iter() }
+}
+
+impl<'a, T: Copy, const N: usize, L: LengthType> IntoIterator
+    for &'a CopyArrayVec<T, N, L>
+{
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T: Copy, const N: usize, L: LengthType> IntoIterator
+    for &'a mut CopyArrayVec<T, N, L>
+{
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem;
+
+    #[test]
+    fn same_layout_as_array_vec() {
+        assert_eq!(
+            mem::size_of::<CopyArrayVec<u16, 7>>(),
+            mem::size_of::<ArrayVec<u16, 7>>()
+        );
+        assert_eq!(
+            mem::align_of::<CopyArrayVec<u16, 7>>(),
+            mem::align_of::<ArrayVec<u16, 7>>()
+        );
+    }
+
+    #[test]
+    fn round_trip_through_array_vec() {
+        let original: CopyArrayVec<u32, 4> = (1..=3).collect();
+        let copy = original;
+
+        let vector: ArrayVec<u32, 4> = copy.into();
+        let back = CopyArrayVec::from(vector);
+
+        assert_eq!(back, original);
+        assert_eq!(back.as_slice(), &[1, 2, 3]);
+    }
+}
@@ -1,28 +1,32 @@
-use crate::ArrayVec;
+use crate::{ArrayVec, LengthType};
 use core::{
     iter::{DoubleEndedIterator, FusedIterator},
-    mem,
+    mem::{self, ManuallyDrop},
     ops::Range,
-    ptr,
+    ptr, slice,
 };
 
+/// A draining iterator over a range of items in an [`ArrayVec`].
+///
+/// This `struct` is created by [`ArrayVec::drain()`].
 #[derive(Debug, PartialEq)]
-pub struct Drain<'a, T, const N: usize> {
-    inner: &'a mut ArrayVec<T, { N }>,
+pub struct Drain<'a, T, const N: usize, L: LengthType = usize> {
+    inner: &'a mut ArrayVec<T, N, L>,
     /// The index of the first item being removed.
     drain_range_start: usize,
     /// The index of the first item after the drained range.
     tail_start: usize,
     tail_length: usize,
-    /// The front of the remaining drained range.
-    head: *mut T,
-    /// One after the last item in the range being drained.
-    tail: *mut T,
+    /// The index of the front of the remaining drained range.
+    head: usize,
+    /// One after the index of the last remaining item in the range being
+    /// drained.
+    tail: usize,
 }
 
-impl<'a, T, const N: usize> Drain<'a, T, { N }> {
+impl<'a, T, const N: usize, L: LengthType> Drain<'a, T, N, L> {
     pub(crate) fn with_range(
-        vector: &'a mut ArrayVec<T, { N }>,
+        vector: &'a mut ArrayVec<T, N, L>,
         range: Range<usize>,
     ) -> Self {
         debug_assert!(
@@ -30,37 +34,163 @@ impl<'a, T, const N: usize> Drain<'a, T, { N }> {
             "The range start must be before end"
         );
         debug_assert!(range.end <= vector.len(), "The range is out of bounds");
-        debug_assert!(
-            core::mem::size_of::<T>() != 0,
-            "We can't deal with zero-sized types"
-        );
 
-        unsafe {
-            let head = vector.as_mut_ptr().add(range.start);
-            let tail = vector.as_mut_ptr().add(range.end);
-            let tail_length = vector.len() - (range.end - range.start);
+        let tail_length = vector.len() - range.end;
 
+        unsafe {
             // prevent a leaked Drain from letting users read from uninitialized
             // memory
             vector.set_len(range.start);
+        }
+
+        Drain {
+            inner: vector,
+            drain_range_start: range.start,
+            tail_start: range.end,
+            tail_length,
+            head: range.start,
+            tail: range.end,
+        }
+    }
 
-            Drain {
-                inner: vector,
-                drain_range_start: range.start,
-                tail_start: range.end,
-                tail_length,
-                head,
-                tail,
+    /// The items which haven't been yielded yet, as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5]);
+    ///
+    /// let mut drain = vector.drain(1..4);
+    /// assert_eq!(drain.as_slice(), &[2, 3, 4]);
+    ///
+    /// drain.next();
+    /// assert_eq!(drain.as_slice(), &[3, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(
+                self.inner.as_ptr().add(self.head),
+                self.tail - self.head,
+            )
+        }
+    }
+
+    /// The items which haven't been yielded yet, as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.inner.as_mut_ptr().add(self.head),
+                self.tail - self.head,
+            )
+        }
+    }
+
+    /// Stop draining, leaving any items which haven't been yielded yet in
+    /// the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5]);
+    ///
+    /// let mut drain = vector.drain(1..4);
+    /// assert_eq!(drain.next(), Some(2));
+    /// drain.keep_rest();
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 3, 4, 5]);
+    /// ```
+    pub fn keep_rest(self) {
+        // we're doing the cleanup ourselves, so skip the normal destructor
+        let mut this = ManuallyDrop::new(self);
+        let remaining = this.tail - this.head;
+
+        unsafe {
+            let base = this.inner.as_mut_ptr();
+            let start = base.add(this.drain_range_start);
+
+            // shuffle the un-yielded items forwards to fill the gap left by
+            // the items we've already yielded, then move the tail up behind
+            // them
+            ptr::copy(base.add(this.head), start, remaining);
+            ptr::copy(
+                base.add(this.tail_start),
+                start.add(remaining),
+                this.tail_length,
+            );
+
+            let new_length =
+                this.drain_range_start + remaining + this.tail_length;
+            this.inner.set_len(new_length);
+        }
+    }
+
+    /// Fill the gap left by the drained range with items from
+    /// `replace_with`, moving the tail to the back of the buffer if more room
+    /// is needed.
+    ///
+    /// This must only be called once every drained item has been yielded.
+    /// The first item which didn't fit is returned.
+    pub(crate) fn fill<I>(&mut self, replace_with: &mut I) -> Option<T>
+    where
+        I: Iterator<Item = T>,
+    {
+        debug_assert_eq!(self.head, self.tail, "The drain must be exhausted");
+
+        // fill the gap we've already got
+        if !self.fill_gap(replace_with) {
+            return None;
+        }
+
+        // then move the tail as far back as possible and try again
+        let new_tail_start = N - self.tail_length;
+
+        if new_tail_start > self.tail_start {
+            unsafe {
+                let base = self.inner.as_mut_ptr();
+                ptr::copy(
+                    base.add(self.tail_start),
+                    base.add(new_tail_start),
+                    self.tail_length,
+                );
+            }
+            self.tail_start = new_tail_start;
+
+            if !self.fill_gap(replace_with) {
+                return None;
             }
         }
+
+        replace_with.next()
     }
 
-    pub fn as_slice(&self) -> &[T] { unimplemented!() }
+    /// Write items into the gap between the drained range's start and the
+    /// tail, returning `true` if the gap was filled before `replace_with`
+    /// ran out.
+    fn fill_gap<I>(&mut self, replace_with: &mut I) -> bool
+    where
+        I: Iterator<Item = T>,
+    {
+        while self.drain_range_start < self.tail_start {
+            match replace_with.next() {
+                Some(item) => unsafe {
+                    let slot =
+                        self.inner.as_mut_ptr().add(self.drain_range_start);
+                    slot.write(item);
+                    // the item is now owned by the vector's head, so it'll be
+                    // kept when the tail is moved up behind it
+                    self.drain_range_start += 1;
+                },
+                None => return false,
+            }
+        }
 
-    pub fn as_mut_slice(&mut self) -> &mut [T] { unimplemented!() }
+        true
+    }
 }
 
-impl<'a, T, const N: usize> Iterator for Drain<'a, T, { N }> {
+impl<'a, T, const N: usize, L: LengthType> Iterator for Drain<'a, T, N, L> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -70,11 +200,9 @@ impl<'a, T, const N: usize> Iterator for Drain<'a, T, { N }> {
         }
 
         unsafe {
-            // The tail points at tne end of our
-            // copy the item onto the stack. The tail
-            let item = self.head.read();
-            // increment the head pointer
-            self.head = self.head.add(1);
+            // copy the item onto the stack then move the head forwards
+            let item = self.inner.as_ptr().add(self.head).read();
+            self.head += 1;
             Some(item)
         }
     }
@@ -84,7 +212,9 @@ impl<'a, T, const N: usize> Iterator for Drain<'a, T, { N }> {
     }
 }
 
-impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, { N }> {
+impl<'a, T, const N: usize, L: LengthType> DoubleEndedIterator
+    for Drain<'a, T, N, L>
+{
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.head == self.tail {
             // No more items
@@ -92,49 +222,43 @@ impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, { N }> {
         }
 
         unsafe {
-            // the tail pointer is one PAST the end of our selection.
-            // Pre-decrement so we're pointing at a valid item before reading
-            self.tail = self.tail.sub(1);
-            let item = self.tail.read();
-            Some(item)
+            // the tail is one PAST the end of our selection. Pre-decrement so
+            // we're pointing at a valid item before reading
+            self.tail -= 1;
+            Some(self.inner.as_ptr().add(self.tail).read())
         }
     }
 }
 
-impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, { N }> {}
-
-impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, { N }> {
-    fn len(&self) -> usize {
-        let size = mem::size_of::<T>();
-        assert!(0 < size && size <= isize::max_value() as usize);
-
-        let difference = (self.tail as isize) - (self.head as isize);
-        debug_assert!(difference >= 0, "Tail should always be after head");
+impl<'a, T, const N: usize, L: LengthType> FusedIterator
+    for Drain<'a, T, N, L>
+{
+}
 
-        difference as usize / size
-    }
+impl<'a, T, const N: usize, L: LengthType> ExactSizeIterator
+    for Drain<'a, T, N, L>
+{
+    fn len(&self) -> usize { self.tail - self.head }
 }
 
-impl<'a, T, const N: usize> Drop for Drain<'a, T, { N }> {
+impl<'a, T, const N: usize, L: LengthType> Drop for Drain<'a, T, N, L> {
     fn drop(&mut self) {
         // remove any remaining items so their destructors can run
-        while let Some(item) = self.next() {
-            mem::drop(item);
-        }
-
-        if self.tail_length == 0 {
-            // there are no items after the drained range
-            return;
-        }
+        self.for_each(mem::drop);
 
         unsafe {
-            let tail_start = self.inner.as_ptr().add(self.tail_start);
-            let drain_range_start =
-                self.inner.as_mut_ptr().add(self.drain_range_start);
+            if self.tail_length > 0 && self.tail_start != self.drain_range_start
+            {
+                let base = self.inner.as_mut_ptr();
 
-            // moves the tail (items after drained range) forwards now that the
-            // drained items are destroyed
-            ptr::copy(tail_start, drain_range_start, self.tail_length);
+                // moves the tail (items after drained range) forwards now
+                // that the drained items are destroyed
+                ptr::copy(
+                    base.add(self.tail_start),
+                    base.add(self.drain_range_start),
+                    self.tail_length,
+                );
+            }
 
             // we can now update the length
             self.inner
@@ -142,3 +266,43 @@ impl<'a, T, const N: usize> Drop for Drain<'a, T, { N }> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drain_the_middle_of_a_vector() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4, 5]);
+
+        let drained: ArrayVec<_, 5> = vector.drain(1..3).collect();
+
+        assert_eq!(drained.as_slice(), &[2, 3]);
+        assert_eq!(vector.as_slice(), &[1, 4, 5]);
+    }
+
+    #[test]
+    fn drain_zero_sized_types() {
+        let mut vector = ArrayVec::from([(); 5]);
+
+        let mut drain = vector.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(()));
+        assert_eq!(drain.as_slice(), &[(), ()]);
+        drop(drain);
+
+        assert_eq!(vector.len(), 2);
+    }
+
+    #[test]
+    fn keep_rest_after_taking_from_both_ends() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4, 5, 6]);
+
+        let mut drain = vector.drain(1..5);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(5));
+        drain.keep_rest();
+
+        assert_eq!(vector.as_slice(), &[1, 3, 4, 6]);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{ArrayVec, LengthType};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+    ops::Range,
+    ptr,
+};
+
+/// An iterator which removes and yields the items in an [`ArrayVec`] that
+/// match a predicate.
+///
+/// This `struct` is created by [`ArrayVec::extract_if()`].
+///
+/// Items are only visited when the iterator is advanced, so dropping it
+/// part-way leaves any unvisited items in the vector.
+pub struct ExtractIf<'a, T, F, const N: usize, L: LengthType = usize>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vector: &'a mut ArrayVec<T, N, L>,
+    /// The index of the next item to look at.
+    index: usize,
+    /// One past the last index we're allowed to look at.
+    end: usize,
+    /// The number of items removed so far.
+    deleted: usize,
+    /// The vector's length before we started.
+    original_length: usize,
+    predicate: F,
+}
+
+impl<'a, T, F, const N: usize, L: LengthType> ExtractIf<'a, T, F, N, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn with_range(
+        vector: &'a mut ArrayVec<T, N, L>,
+        range: Range<usize>,
+        predicate: F,
+    ) -> Self {
+        debug_assert!(
+            range.start <= range.end,
+            "The range start must be before end"
+        );
+        debug_assert!(range.end <= vector.len(), "The range is out of bounds");
+
+        let original_length = vector.len();
+
+        unsafe {
+            // prevent a leaked ExtractIf from letting users read items which
+            // have been moved out
+            vector.set_len(0);
+        }
+
+        ExtractIf {
+            vector,
+            index: range.start,
+            end: range.end,
+            deleted: 0,
+            original_length,
+            predicate,
+        }
+    }
+}
+
+impl<'a, T, F, const N: usize, L: LengthType> Iterator
+    for ExtractIf<'a, T, F, N, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.end {
+            unsafe {
+                let current = self.vector.as_mut_ptr().add(self.index);
+                let extract = (self.predicate)(&mut *current);
+                // only mark the item as visited after the predicate has run,
+                // that way it gets kept if the predicate panics
+                self.index += 1;
+
+                if extract {
+                    self.deleted += 1;
+                    return Some(ptr::read(current));
+                } else if self.deleted > 0 {
+                    // move the item back to fill the hole
+                    let hole = current.sub(self.deleted);
+                    ptr::copy_nonoverlapping(current, hole, 1);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.index))
+    }
+}
+
+impl<'a, T, F, const N: usize, L: LengthType> FusedIterator
+    for ExtractIf<'a, T, F, N, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+}
+
+impl<'a, T: Debug, F, const N: usize, L: LengthType> Debug
+    for ExtractIf<'a, T, F, N, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let unvisited = unsafe {
+            core::slice::from_raw_parts(
+                self.vector.as_ptr().add(self.index),
+                self.end - self.index,
+            )
+        };
+
+        f.debug_struct("ExtractIf")
+            .field("unvisited", &unvisited)
+            .finish()
+    }
+}
+
+impl<'a, T, F, const N: usize, L: LengthType> Drop for ExtractIf<'a, T, F, N, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe {
+            if self.deleted > 0 {
+                // shuffle everything we haven't visited back to fill the
+                // hole left by the extracted items
+                let base = self.vector.as_mut_ptr();
+                ptr::copy(
+                    base.add(self.index),
+                    base.add(self.index - self.deleted),
+                    self.original_length - self.index,
+                );
+            }
+
+            self.vector.set_len(self.original_length - self.deleted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_part_way_keeps_unvisited_items() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4, 5, 6]);
+
+        let mut evens = vector.extract_if(.., |x| *x % 2 == 0);
+        assert_eq!(evens.next(), Some(2));
+        drop(evens);
+
+        assert_eq!(vector.as_slice(), &[1, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn only_scan_a_sub_range() {
+        let mut vector = ArrayVec::from([2, 4, 6, 8, 10]);
+
+        let extracted: ArrayVec<_, 5> =
+            vector.extract_if(1..3, |_| true).collect();
+
+        assert_eq!(extracted.as_slice(), &[4, 6]);
+        assert_eq!(vector.as_slice(), &[2, 8, 10]);
+    }
+
+    #[test]
+    fn panicking_predicate_keeps_the_current_item() {
+        extern crate std;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut vector = ArrayVec::from([1, 2, 3, 4]);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector
+                .extract_if(.., |x| match *x {
+                    1 => true,
+                    3 => panic!(),
+                    _ => false,
+                })
+                .for_each(drop);
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(vector.as_slice(), &[2, 3, 4]);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{ArrayVec, LengthType};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::{DoubleEndedIterator, FusedIterator},
+    ptr, slice,
+};
+
+/// An iterator which moves items out of an [`ArrayVec`].
+///
+/// This `struct` is created by the `into_iter()` method on [`ArrayVec`]
+/// (provided by the [`IntoIterator`] trait).
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// let vector = ArrayVec::from([
+///     "first".to_owned(),
+///     "second".to_owned(),
+///     "third".to_owned(),
+/// ]);
+///
+/// let mut iter = vector.into_iter();
+///
+/// assert_eq!(iter.next(), Some("first".to_owned()));
+/// assert_eq!(iter.next_back(), Some("third".to_owned()));
+/// assert_eq!(iter.as_slice(), &["second".to_owned()]);
+/// ```
+pub struct IntoIter<T, const N: usize, L: LengthType = usize> {
+    /// The vector we're taking items from. Its length is always `0` so it
+    /// won't try to drop anything, we keep track of the items ourselves.
+    vector: ArrayVec<T, N, L>,
+    /// The index of the next item to yield from the front.
+    start: usize,
+    /// One past the index of the next item to yield from the back.
+    end: usize,
+}
+
+impl<T, const N: usize, L: LengthType> IntoIter<T, N, L> {
+    pub(crate) fn new(mut vector: ArrayVec<T, N, L>) -> Self {
+        let end = vector.len();
+
+        unsafe {
+            // ownership of the items is transferred to the iterator, make sure
+            // the vector won't drop them when it goes out of scope
+            vector.set_len(0);
+        }
+
+        IntoIter {
+            vector,
+            start: 0,
+            end,
+        }
+    }
+
+    /// The remaining items, as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(
+                self.vector.as_ptr().add(self.start),
+                self.end - self.start,
+            )
+        }
+    }
+
+    /// The remaining items, as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.vector.as_mut_ptr().add(self.start),
+                self.end - self.start,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize, L: LengthType> Iterator for IntoIter<T, N, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            // No more items
+            return None;
+        }
+
+        unsafe {
+            let item = self.vector.as_ptr().add(self.start).read();
+            self.start += 1;
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<T, const N: usize, L: LengthType> DoubleEndedIterator
+    for IntoIter<T, N, L>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            // No more items
+            return None;
+        }
+
+        unsafe {
+            // end is one PAST the last item, so decrement before reading
+            self.end -= 1;
+            Some(self.vector.as_ptr().add(self.end).read())
+        }
+    }
+}
+
+impl<T, const N: usize, L: LengthType> ExactSizeIterator for IntoIter<T, N, L> {
+    fn len(&self) -> usize { self.end - self.start }
+}
+
+impl<T, const N: usize, L: LengthType> FusedIterator for IntoIter<T, N, L> {}
+
+impl<T: Clone, const N: usize, L: LengthType> Clone for IntoIter<T, N, L> {
+    fn clone(&self) -> IntoIter<T, N, L> {
+        let mut vector: ArrayVec<T, N, L> = ArrayVec::new();
+
+        for item in self.as_slice() {
+            unsafe {
+                // the remaining items fit in the original, so they'll fit in
+                // the clone
+                vector.push_unchecked(item.clone());
+            }
+        }
+
+        IntoIter::new(vector)
+    }
+}
+
+impl<T: Debug, const N: usize, L: LengthType> Debug for IntoIter<T, N, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, const N: usize, L: LengthType> Drop for IntoIter<T, N, L> {
+    fn drop(&mut self) {
+        let remaining: *mut [T] = self.as_mut_slice();
+
+        // Make sure a panicking destructor can't cause a double-drop
+        self.start = self.end;
+
+        unsafe {
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::OnDropped;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn unconsumed_items_are_dropped() {
+        let counter = AtomicUsize::new(0);
+        let mut vector: ArrayVec<OnDropped<'_>, 5> = ArrayVec::new();
+        for _ in 0..4 {
+            vector.push(OnDropped(&counter));
+        }
+
+        let mut iter = vector.into_iter();
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        drop(iter);
+        assert_eq!(counter.load(Ordering::Relaxed), 2);
+
+        drop(first);
+        drop(last);
+        assert_eq!(counter.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn zero_sized_types() {
+        let vector = ArrayVec::from([(), (), ()]);
+
+        let mut iter = vector.into_iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(()));
+        assert_eq!(iter.next_back(), Some(()));
+        assert_eq!(iter.as_slice(), &[()]);
+        assert_eq!(iter.next(), Some(()));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn clone_only_copies_the_remaining_items() {
+        let mut iter = ArrayVec::from([1, 2, 3, 4]).into_iter();
+        iter.next();
+
+        let mut cloned = iter.clone();
+        cloned.as_mut_slice()[0] = 42;
+
+        assert_eq!(cloned.as_slice(), &[42, 3, 4]);
+        assert_eq!(iter.as_slice(), &[2, 3, 4]);
+    }
+}
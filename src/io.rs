@@ -0,0 +1,99 @@
+//! [`std::io`] integration, enabled with the `std` feature.
+
+use crate::{ArrayVec, LengthType};
+use std::io::{self, Read, Write};
+
+/// Appends bytes to the end of the vector.
+///
+/// Writes are cut short when the vector doesn't have enough room, and
+/// nothing is written once it is full. That means [`Write::write_all()`]
+/// fails with [`io::ErrorKind::WriteZero`] instead of silently dropping
+/// bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// use std::io::{ErrorKind, Write};
+///
+/// let mut buffer: ArrayVec<u8, 8> = ArrayVec::new();
+///
+/// write!(buffer, "{}", 1234).unwrap();
+/// assert_eq!(buffer.as_slice(), b"1234");
+///
+/// assert_eq!(buffer.write(b"567890").unwrap(), 4);
+/// assert_eq!(buffer.as_slice(), b"12345678");
+///
+/// let err = buffer.write_all(b"9").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::WriteZero);
+/// ```
+impl<const N: usize, L: LengthType> Write for ArrayVec<u8, N, L> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = usize::min(buf.len(), self.remaining_capacity());
+
+        unsafe {
+            // we've already made sure there's enough room
+            self.as_mut_ptr()
+                .add(self.len())
+                .copy_from_nonoverlapping(buf.as_ptr(), len);
+            self.set_len(self.len() + len);
+        }
+
+        Ok(len)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+/// Reads bytes from the front of the vector, removing them as they are read.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// use std::io::Read;
+///
+/// let mut buffer = ArrayVec::from(*b"Hello, World!");
+///
+/// let mut hello = [0; 5];
+/// buffer.read_exact(&mut hello).unwrap();
+/// assert_eq!(&hello, b"Hello");
+/// assert_eq!(buffer.as_slice(), b", World!");
+/// ```
+impl<const N: usize, L: LengthType> Read for ArrayVec<u8, N, L> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = usize::min(buf.len(), self.len());
+
+        buf[..len].copy_from_slice(&self[..len]);
+        self.drain(..len);
+
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_between_buffers() {
+        let mut src = ArrayVec::from(*b"some bytes");
+        let mut dest: ArrayVec<u8, 4> = ArrayVec::new();
+
+        let err = io::copy(&mut src, &mut dest).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(dest.as_slice(), b"some");
+    }
+
+    #[test]
+    fn reading_from_an_empty_vector() {
+        let mut buffer: ArrayVec<u8, 4> = ArrayVec::new();
+        let mut dest = [0; 4];
+
+        assert_eq!(buffer.read(&mut dest).unwrap(), 0);
+    }
+}
@@ -0,0 +1,149 @@
+use core::mem;
+
+/// An unsigned integer type which can be used to store the length of an
+/// [`ArrayVec`].
+///
+/// This is implemented for `u8`, `u16`, `u32` and `usize`. Using a smaller
+/// type than `usize` (the default) can make small vectors considerably more
+/// compact.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// use core::mem::size_of;
+///
+/// let mut vector: ArrayVec<u8, 15, u8> = ArrayVec::new();
+/// vector.push(42);
+///
+/// assert_eq!(vector.len(), 1);
+/// assert_eq!(vector.capacity(), 15);
+/// assert_eq!(size_of::<ArrayVec<u8, 15, u8>>(), 16);
+/// ```
+///
+/// Trying to use a length type which can't represent the capacity is a
+/// compile error.
+///
+/// ```rust,compile_fail
+/// use const_arrayvec::ArrayVec;
+///
+/// let vector: ArrayVec<u8, 256, u8> = ArrayVec::new();
+/// ```
+///
+/// [`ArrayVec`]: crate::ArrayVec
+pub trait LengthType: Copy + private::Sealed {
+    /// The largest length this type can represent.
+    const MAX: usize;
+    /// A length of zero.
+    const ZERO: Self;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+impl LengthType for u8 {
+    const MAX: usize = u8::MAX as usize;
+    const ZERO: Self = 0;
+}
+
+impl LengthType for u16 {
+    const MAX: usize = u16::MAX as usize;
+    const ZERO: Self = 0;
+}
+
+impl LengthType for u32 {
+    const MAX: usize = if mem::size_of::<usize>() < mem::size_of::<u32>() {
+        usize::MAX
+    } else {
+        u32::MAX as usize
+    };
+    const ZERO: Self = 0;
+}
+
+impl LengthType for usize {
+    const MAX: usize = usize::MAX;
+    const ZERO: Self = 0;
+}
+
+/// Read a length as a `usize`.
+///
+/// Trait methods can't be called in a `const fn`, so we go through a pointer
+/// to the matching primitive instead. This is fine because [`LengthType`] is
+/// sealed, and each implementation has a different size (except `u32` and
+/// `usize` on 32-bit platforms, which are interchangeable anyway).
+#[inline]
+pub(crate) const fn get<L: LengthType>(length: &L) -> usize {
+    let ptr = length as *const L;
+
+    unsafe {
+        match mem::size_of::<L>() {
+            1 => *(ptr as *const u8) as usize,
+            2 => *(ptr as *const u16) as usize,
+            4 => *(ptr as *const u32) as usize,
+            _ => *(ptr as *const usize),
+        }
+    }
+}
+
+/// Update a length from a `usize`.
+///
+/// The caller must make sure `value` is no greater than [`LengthType::MAX`].
+#[inline]
+pub(crate) const fn set<L: LengthType>(length: &mut L, value: usize) {
+    debug_assert!(value <= L::MAX);
+    let ptr = length as *mut L;
+
+    unsafe {
+        match mem::size_of::<L>() {
+            1 => *(ptr as *mut u8) = value as u8,
+            2 => *(ptr as *mut u16) = value as u16,
+            4 => *(ptr as *mut u32) = value as u32,
+            _ => *(ptr as *mut usize) = value,
+        }
+    }
+}
+
+/// A compile-time assertion that a vector with a capacity of `N` can store
+/// its length in `L`.
+pub(crate) struct AssertLengthFits<L, const N: usize>(L);
+
+impl<L: LengthType, const N: usize> AssertLengthFits<L, N> {
+    pub(crate) const OK: () =
+        assert!(N <= L::MAX, "The capacity is too large for the length type");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayVec;
+    use core::mem;
+
+    #[test]
+    fn compact_lengths_round_trip() {
+        let mut vector: ArrayVec<u8, 255, u8> = ArrayVec::new();
+        vector.extend(0..=254);
+        assert!(vector.is_full());
+        assert_eq!(vector.len(), 255);
+
+        let mut wide: ArrayVec<u16, 300, u16> = ArrayVec::new();
+        wide.extend(0..300);
+        wide.truncate(260);
+        assert_eq!(wide.len(), 260);
+        assert_eq!(wide.pop(), Some(259));
+
+        let short: ArrayVec<u32, 4, u8> = ArrayVec::from_array([1, 2, 3, 4]);
+        assert_eq!(short, ArrayVec::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn smaller_length_types_shrink_the_vector() {
+        assert_eq!(mem::size_of::<ArrayVec<u8, 15, u8>>(), 16);
+        assert_eq!(mem::size_of::<ArrayVec<u8, 14, u16>>(), 16);
+        assert_eq!(mem::size_of::<ArrayVec<u8, 12, u32>>(), 16);
+    }
+}
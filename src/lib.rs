@@ -1,17 +1,57 @@
 #![no_std]
-#![feature(const_generics)]
-#![allow(incomplete_features)]
-
+#![cfg_attr(feature = "nightly", feature(dropck_eyepatch))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod alloc_impls;
+pub mod array_deque;
+pub mod array_map;
+pub mod array_set;
+mod array_string;
+mod copy_array_vec;
 mod drain;
+mod extract_if;
+mod into_iter;
+#[cfg(feature = "std")]
+mod io;
+mod length;
+mod macros;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "alloc")]
+pub mod small_vec;
+mod splice;
+#[cfg(test)]
+pub(crate) mod test_helpers;
 
+pub use array_deque::ArrayDeque;
+pub use array_map::ArrayMap;
+pub use array_set::ArraySet;
+pub use array_string::ArrayString;
+pub use copy_array_vec::CopyArrayVec;
 pub use drain::Drain;
-
+pub use extract_if::ExtractIf;
+pub use into_iter::IntoIter;
+pub use length::LengthType;
+#[doc(hidden)]
+pub use macros::__private;
+#[cfg(feature = "alloc")]
+pub use small_vec::SmallVec;
+pub use splice::Splice;
+
+use crate::length::AssertLengthFits;
 use core::{
     cmp::Ordering,
+    convert::{Infallible, TryFrom},
     fmt::{self, Debug, Display, Formatter},
     hash::{Hash, Hasher},
+    iter::FromIterator,
     mem::{self, MaybeUninit},
-    ops::{Deref, DerefMut, Index, IndexMut, Range},
+    ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds},
     ptr, slice,
 };
 
@@ -24,20 +64,96 @@ macro_rules! out_of_bounds {
                 "(): index {} is out of bounds in vector of length {}"
             ),
             $index, $len
-        );
+        )
+    };
+}
+
+/// Turn any [`RangeBounds`] into a [`Range`] which is valid for a vector of
+/// length `len`, panicking if it's out of bounds.
+macro_rules! checked_range {
+    ($method:expr, $range:expr, $len:expr) => {{
+        let len = $len;
+        let Range { start, end } = resolve_range(&$range, len);
+
+        if end > len {
+            out_of_bounds!($method, end, len);
+        }
+        if start > end {
+            panic!(
+                concat!(
+                    "ArrayVec::",
+                    $method,
+                    "(): range start {} is greater than range end {}"
+                ),
+                start, end
+            );
+        }
+
+        start..end
+    }};
+}
+
+/// A compile-time assertion that `N` items will always fit in a vector with
+/// a capacity of `M`.
+struct AssertFits<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> AssertFits<N, M> {
+    const OK: () = assert!(M >= N, "The capacity is too small");
+}
+
+/// Turn any [`RangeBounds`] into a concrete [`Range`] for a vector of length
+/// `len`.
+///
+/// Bounds which overflow saturate to `usize::MAX` so they'll fail the
+/// caller's bounds checks.
+fn resolve_range<R: RangeBounds<usize>>(range: &R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
     };
+
+    start..end
 }
 
 /// A vector type backed by a fixed-length array.
-pub struct ArrayVec<T, const N: usize> {
+// Note: the layout must stay in sync with CopyArrayVec
+#[repr(C)]
+pub struct ArrayVec<T, const N: usize, L: LengthType = usize> {
     items: [MaybeUninit<T>; N],
-    length: usize,
+    length: L,
 }
 
-impl<T, const N: usize> ArrayVec<T, { N }> {
+impl<T, const N: usize, L: LengthType> ArrayVec<T, N, L> {
     /// Create a new, empty [`ArrayVec`].
+    ///
+    /// This is a `const fn`, so pre-populated vectors can be created at
+    /// compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// static PRIMES: ArrayVec<u32, 8> = {
+    ///     let mut primes = ArrayVec::new();
+    ///     primes.push(2);
+    ///     primes.push(3);
+    ///     primes.push(5);
+    ///     primes
+    /// };
+    ///
+    /// assert_eq!(PRIMES.as_slice(), &[2, 3, 5]);
+    /// ```
     #[inline]
-    pub fn new() -> ArrayVec<T, { N }> {
+    pub const fn new() -> ArrayVec<T, N, L> {
+        let () = AssertLengthFits::<L, N>::OK;
+
         unsafe {
             ArrayVec {
                 // this is safe because we've asked for a big block of
@@ -45,13 +161,155 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
                 // an array of uninitialized items,
                 // which perfectly valid for [MaybeUninit<_>; N]
                 items: MaybeUninit::uninit().assume_init(),
-                length: 0,
+                length: L::ZERO,
+            }
+        }
+    }
+
+    /// Create a new, full [`ArrayVec`] from an array.
+    ///
+    /// This is the same as the [`From`] impl, except it can be used in
+    /// `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// const DIGITS: ArrayVec<u8, 3> = ArrayVec::from_array([1, 2, 3]);
+    ///
+    /// assert!(DIGITS.is_full());
+    /// assert_eq!(DIGITS.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub const fn from_array(array: [T; N]) -> ArrayVec<T, N, L> {
+        // ownership of the items is transferred to the backing buffer, make
+        // sure the original array's destructors aren't called prematurely
+        let array = mem::ManuallyDrop::new(array);
+
+        let () = AssertLengthFits::<L, N>::OK;
+        let mut length = L::ZERO;
+        length::set(&mut length, N);
+
+        ArrayVec {
+            // Note: Safe because a [T; N] is identical to [MaybeUninit<T>; N]
+            items: unsafe {
+                ptr::read(
+                    &array as *const mem::ManuallyDrop<[T; N]>
+                        as *const [MaybeUninit<T>; N],
+                )
+            },
+            length,
+        }
+    }
+
+    /// Create a new [`ArrayVec`] from the first `len` items in an array,
+    /// dropping the rest.
+    ///
+    /// # Panics
+    ///
+    /// `len` must not be greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let vector: ArrayVec<_, 4> = ArrayVec::from_array_len([1, 2, 3, 4], 2);
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2]);
+    /// assert_eq!(vector.capacity(), 4);
+    /// ```
+    pub fn from_array_len(array: [T; N], len: usize) -> ArrayVec<T, N, L> {
+        assert!(
+            len <= N,
+            "ArrayVec::from_array_len(): length {} is greater than the capacity {}",
+            len,
+            N
+        );
+
+        let mut vector = ArrayVec::from_array(array);
+        vector.truncate(len);
+        vector
+    }
+
+    /// Create a new [`ArrayVec`] with `len` items, where each item is the
+    /// result of calling `f` with its index.
+    ///
+    /// # Panics
+    ///
+    /// `len` must not be greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let vector: ArrayVec<usize, 8> = ArrayVec::from_fn(4, |i| i * i);
+    ///
+    /// assert_eq!(vector.as_slice(), &[0, 1, 4, 9]);
+    /// ```
+    pub fn from_fn<F>(len: usize, mut f: F) -> ArrayVec<T, N, L>
+    where
+        F: FnMut(usize) -> T,
+    {
+        match ArrayVec::try_from_fn(len, |i| Ok::<T, Infallible>(f(i))) {
+            Ok(vector) => vector,
+            Err(e) => match e {},
+        }
+    }
+
+    /// Create a new [`ArrayVec`] with `len` items, where each item is the
+    /// result of calling `f` with its index, stopping at the first error.
+    ///
+    /// # Panics
+    ///
+    /// `len` must not be greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let digits = ["1", "2", "3"];
+    /// let parsed: Result<ArrayVec<u32, 4>, _> =
+    ///     ArrayVec::try_from_fn(digits.len(), |i| digits[i].parse());
+    /// assert_eq!(parsed.unwrap().as_slice(), &[1, 2, 3]);
+    ///
+    /// let words = ["1", "two"];
+    /// let parsed: Result<ArrayVec<u32, 4>, _> =
+    ///     ArrayVec::try_from_fn(words.len(), |i| words[i].parse());
+    /// assert!(parsed.is_err());
+    /// ```
+    pub fn try_from_fn<F, E>(
+        len: usize,
+        mut f: F,
+    ) -> Result<ArrayVec<T, N, L>, E>
+    where
+        F: FnMut(usize) -> Result<T, E>,
+    {
+        assert!(
+            len <= N,
+            "ArrayVec::try_from_fn(): length {} is greater than the capacity {}",
+            len,
+            N
+        );
+
+        let mut vector = ArrayVec::new();
+
+        for index in 0..len {
+            let item = f(index)?;
+
+            unsafe {
+                // we've already checked the capacity
+                vector.push_unchecked(item);
             }
         }
+
+        Ok(vector)
     }
 
     #[inline]
-    pub const fn len(&self) -> usize { self.length }
+    pub const fn len(&self) -> usize { length::get(&self.length) }
 
     #[inline]
     pub const fn is_empty(&self) -> bool { self.len() == 0 }
@@ -68,10 +326,12 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     pub const fn is_full(&self) -> bool { self.len() >= self.capacity() }
 
     #[inline]
-    pub fn as_ptr(&self) -> *const T { self.items.as_ptr() as *const T }
+    pub const fn as_ptr(&self) -> *const T { self.items.as_ptr() as *const T }
 
     #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut T { self.items.as_mut_ptr() as *mut T }
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.items.as_mut_ptr() as *mut T
+    }
 
     /// Add an item to the end of the vector.
     ///
@@ -88,10 +348,14 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     /// assert_eq!(vector.len(), 1);
     /// assert_eq!(vector[0], 42);
     /// ```
-    pub fn push(&mut self, item: T) {
-        match self.try_push(item) {
-            Ok(_) => {},
-            Err(e) => panic!("Push failed: {}", e),
+    pub const fn push(&mut self, item: T) {
+        if self.is_full() {
+            // Note: formatting isn't allowed in a const fn
+            panic!("Push failed: Insufficient capacity");
+        }
+
+        unsafe {
+            self.push_unchecked(item);
         }
     }
 
@@ -110,7 +374,7 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     ///
     /// assert_eq!(vector.try_push(42), Err(CapacityError(42)));
     /// ```
-    pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+    pub const fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
         if self.is_full() {
             Err(CapacityError(item))
         } else {
@@ -129,7 +393,7 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     /// large.
     ///
     /// This method uses *debug assertions* to detect overflows in debug builds.
-    pub unsafe fn push_unchecked(&mut self, item: T) {
+    pub const unsafe fn push_unchecked(&mut self, item: T) {
         debug_assert!(!self.is_full());
         let len = self.len();
 
@@ -149,9 +413,9 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     /// elements the vector thinks it contains, without adding or removing any
     /// elements. Use with care.
     #[inline]
-    pub unsafe fn set_len(&mut self, new_length: usize) {
+    pub const unsafe fn set_len(&mut self, new_length: usize) {
         debug_assert!(new_length <= self.capacity());
-        self.length = new_length;
+        length::set(&mut self.length, new_length);
     }
 
     /// Remove an item from the end of the vector.
@@ -172,7 +436,7 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     /// assert_eq!(got, Some(34));
     /// assert_eq!(vector.len(), 1);
     /// ```
-    pub fn pop(&mut self) -> Option<T> {
+    pub const fn pop(&mut self) -> Option<T> {
         if self.is_empty() {
             return None;
         }
@@ -194,8 +458,10 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
                 self.set_len(new_length);
 
                 let start = self.as_mut_ptr().add(new_length);
-                let tail: *mut [T] =
-                    slice::from_raw_parts_mut(start, num_elements_to_remove);
+                let tail: *mut [T] = ptr::slice_from_raw_parts_mut(
+                    start,
+                    num_elements_to_remove,
+                );
 
                 ptr::drop_in_place(tail);
             }
@@ -537,163 +803,1409 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
         item
     }
 
-    #[inline]
-    pub fn as_slice(&self) -> &[T] { self.deref() }
-
-    #[inline]
-    pub fn as_slice_mut(&mut self) -> &mut [T] { self.deref_mut() }
+    /// Keep only the items for which `predicate` returns `true`, removing
+    /// the rest while preserving the order of the retained items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// vector.retain(|&x| x % 2 == 0);
+    ///
+    /// assert_eq!(vector.as_slice(), &[2, 4, 6]);
+    /// ```
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| predicate(item));
+    }
 
-    pub fn try_extend_from_slice(
-        &mut self,
-        other: &[T],
-    ) -> Result<(), CapacityError<()>>
+    /// Keep only the items for which `predicate` returns `true`, passing a
+    /// mutable reference to each item so it can be updated in-place.
+    ///
+    /// Each item is visited exactly once and in order. If `predicate`
+    /// panics, the items which haven't been visited yet are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4]);
+    ///
+    /// vector.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x != 20
+    /// });
+    ///
+    /// assert_eq!(vector.as_slice(), &[10, 30, 40]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut predicate: F)
     where
-        T: Copy,
+        F: FnMut(&mut T) -> bool,
     {
-        if self.remaining_capacity() < other.len() {
-            return Err(CapacityError(()));
+        /// Shifts any unvisited items back over the hole left by deleted
+        /// items and restores the length, even if the predicate panics.
+        struct BackshiftOnDrop<'a, T, const N: usize, L: LengthType> {
+            vector: &'a mut ArrayVec<T, N, L>,
+            processed: usize,
+            deleted: usize,
+            original_length: usize,
         }
 
-        let self_len = self.len();
-        let other_len = other.len();
+        impl<'a, T, const N: usize, L: LengthType> Drop
+            for BackshiftOnDrop<'a, T, N, L>
+        {
+            fn drop(&mut self) {
+                unsafe {
+                    if self.deleted > 0 {
+                        let base = self.vector.as_mut_ptr();
+                        ptr::copy(
+                            base.add(self.processed),
+                            base.add(self.processed - self.deleted),
+                            self.original_length - self.processed,
+                        );
+                    }
+
+                    self.vector.set_len(self.original_length - self.deleted);
+                }
+            }
+        }
+
+        let original_length = self.len();
 
         unsafe {
-            let dst = self.as_mut_ptr().add(self_len);
-            // Note: we have a mutable reference to self, so it's not possible
-            // for the two arrays to overlap
-            ptr::copy_nonoverlapping(other.as_ptr(), dst, other_len);
-            self.set_len(self_len + other_len);
+            // "pre-poop our pants" so a leaked guard can't expose items which
+            // have already been dropped
+            self.set_len(0);
         }
-        Ok(())
-    }
 
-    #[inline]
-    pub fn drain(&mut self, range: Range<usize>) -> Drain<'_, T, { N }> {
-        Drain::with_range(self, range)
-    }
-}
+        let mut guard = BackshiftOnDrop {
+            vector: self,
+            processed: 0,
+            deleted: 0,
+            original_length,
+        };
 
-impl<T, const N: usize> Deref for ArrayVec<T, { N }> {
-    type Target = [T];
+        while guard.processed < original_length {
+            unsafe {
+                let current = guard.vector.as_mut_ptr().add(guard.processed);
+
+                if predicate(&mut *current) {
+                    if guard.deleted > 0 {
+                        // move the item back to fill the hole
+                        let hole = current.sub(guard.deleted);
+                        ptr::copy_nonoverlapping(current, hole, 1);
+                    }
+                    guard.processed += 1;
+                } else {
+                    // mark the item as removed before running its destructor
+                    // in case it panics
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    ptr::drop_in_place(current);
+                }
+            }
+        }
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+        // the guard will update the vector's length
     }
-}
 
-impl<T, const N: usize> DerefMut for ArrayVec<T, { N }> {
+    /// Remove consecutive repeated items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 1, 2, 3, 3, 3, 1]);
+    ///
+    /// vector.dedup();
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 1]);
+    /// ```
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
     }
-}
 
-impl<T, const N: usize> Drop for ArrayVec<T, { N }> {
-    /// Makes sure all items are cleaned up once you're done with the
-    /// [`ArrayVec`].
+    /// Remove consecutive items which resolve to the same key.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use core::{mem, sync::atomic::{AtomicUsize, Ordering}};
     /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([10, 11, 20, 35, 31, 12]);
     ///
-    /// // create a dummy type which increments a number when dropped
-    ///
-    /// struct OnDropped<'a>(&'a AtomicUsize);
-    ///
-    /// impl<'a> Drop for OnDropped<'a> {
-    ///   fn drop(&mut self) { self.0.fetch_add(1, Ordering::Relaxed); }
-    /// }
+    /// vector.dedup_by_key(|x| *x / 10);
     ///
-    /// // create our vector
-    /// let mut vector: ArrayVec<OnDropped<'_>, 5> = ArrayVec::new();
+    /// assert_eq!(vector.as_slice(), &[10, 20, 35, 12]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Remove consecutive items which satisfy an equality relation.
     ///
-    /// // then set up our counter
-    /// let counter = AtomicUsize::new(0);
+    /// The `same_bucket` function is passed the current item and the last
+    /// item that was kept. If it returns `true`, the current item is
+    /// removed.
     ///
-    /// // and add a couple `OnDropped`'s to the vector
-    /// vector.push(OnDropped(&counter));
-    /// vector.push(OnDropped(&counter));
-    /// vector.push(OnDropped(&counter));
+    /// # Examples
     ///
-    /// // the vector is still live so our counter shouldn't have changed
-    /// assert_eq!(counter.load(Ordering::Relaxed), 0);
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from(["foo", "bar", "Bar", "baz", "BAR"]);
     ///
-    /// // explicitly drop the vector
-    /// mem::drop(vector);
+    /// vector.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
     ///
-    /// // and the counter should have updated
-    /// assert_eq!(counter.load(Ordering::Relaxed), 3);
+    /// assert_eq!(vector.as_slice(), &["foo", "bar", "baz", "BAR"]);
     /// ```
-    #[inline]
-    fn drop(&mut self) {
-        // Makes sure the destructors for all items are run.
-        self.clear();
-    }
-}
-
-impl<T, const N: usize> AsRef<[T]> for ArrayVec<T, { N }> {
-    #[inline]
-    fn as_ref(&self) -> &[T] { self.as_slice() }
-}
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        /// Moves the unread items back over the gap left by removed items
+        /// and restores the length, even if `same_bucket` panics.
+        struct FillGapOnDrop<'a, T, const N: usize, L: LengthType> {
+            vector: &'a mut ArrayVec<T, N, L>,
+            /// The index of the next item to look at.
+            read: usize,
+            /// The index of the next free slot.
+            write: usize,
+            original_length: usize,
+        }
 
-impl<T, const N: usize> AsMut<[T]> for ArrayVec<T, { N }> {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
-}
+        impl<'a, T, const N: usize, L: LengthType> Drop for FillGapOnDrop<'a, T, N, L> {
+            fn drop(&mut self) {
+                unsafe {
+                    let base = self.vector.as_mut_ptr();
+                    let unread = self.original_length - self.read;
+                    ptr::copy(
+                        base.add(self.read),
+                        base.add(self.write),
+                        unread,
+                    );
+
+                    self.vector.set_len(self.write + unread);
+                }
+            }
+        }
 
-impl<T: Debug, const N: usize> Debug for ArrayVec<T, { N }> {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.as_slice().fmt(f)
-    }
-}
+        let original_length = self.len();
+        if original_length <= 1 {
+            return;
+        }
 
-impl<T: PartialEq, const N: usize, const M: usize> PartialEq<ArrayVec<T, { M }>>
-    for ArrayVec<T, { N }>
-{
-    #[inline]
-    fn eq(&self, other: &ArrayVec<T, { M }>) -> bool {
-        self.as_slice() == other.as_slice()
-    }
-}
+        unsafe {
+            // "pre-poop our pants" so a leaked guard can't expose items which
+            // have already been dropped
+            self.set_len(0);
+        }
 
-impl<T: PartialEq, const N: usize> PartialEq<[T]> for ArrayVec<T, { N }> {
-    #[inline]
-    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
-}
+        // the first item is always kept
+        let mut gap = FillGapOnDrop {
+            vector: self,
+            read: 1,
+            write: 1,
+            original_length,
+        };
 
-impl<T: Eq, const N: usize> Eq for ArrayVec<T, { N }> {}
+        while gap.read < original_length {
+            unsafe {
+                let base = gap.vector.as_mut_ptr();
+                let current = base.add(gap.read);
+                let previous = base.add(gap.write - 1);
+
+                if same_bucket(&mut *current, &mut *previous) {
+                    // mark the item as removed before running its destructor
+                    // in case it panics
+                    gap.read += 1;
+                    ptr::drop_in_place(current);
+                } else {
+                    ptr::copy(current, base.add(gap.write), 1);
+                    gap.read += 1;
+                    gap.write += 1;
+                }
+            }
+        }
 
-impl<T: PartialOrd, const N: usize> PartialOrd for ArrayVec<T, { N }> {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+        // the guard will update the vector's length
     }
-}
 
-impl<T: Ord, const N: usize> Ord for ArrayVec<T, { N }> {
     #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.as_slice().cmp(other.as_slice())
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
-}
-
-impl<T: Hash, const N: usize> Hash for ArrayVec<T, { N }> {
-    #[inline]
-    fn hash<H: Hasher>(&self, hasher: &mut H) { self.as_slice().hash(hasher); }
-}
 
-impl<T, const N: usize> Default for ArrayVec<T, { N }> {
     #[inline]
-    fn default() -> Self { ArrayVec::new() }
-}
+    pub fn as_slice_mut(&mut self) -> &mut [T] { self.deref_mut() }
 
-impl<Ix, T, const N: usize> Index<Ix> for ArrayVec<T, { N }>
-where
-    [T]: Index<Ix>,
+    /// Clone and add every item in a slice to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// The vector must have enough space for every item in the slice (see
+    /// [`ArrayVec::try_extend_from_slice()`]).
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        if let Err(e) = self.try_extend_from_slice(other) {
+            panic!("Extend failed: {}", e);
+        }
+    }
+
+    /// Try to clone and add every item in a slice to the end of the vector.
+    ///
+    /// Nothing is added if there isn't enough room for the entire slice. If a
+    /// call to `clone()` panics, the items cloned so far are kept.
+    ///
+    /// [`Copy`] items can be added with a single `memcpy` using
+    /// [`ArrayVec::try_extend_from_slice_copy()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<String, 3> = ArrayVec::new();
+    ///
+    /// vector.try_extend_from_slice(&["a".to_owned(), "b".to_owned()]).unwrap();
+    /// assert_eq!(vector.as_slice(), &["a", "b"]);
+    ///
+    /// let got = vector.try_extend_from_slice(&["c".to_owned(), "d".to_owned()]);
+    /// assert_eq!(got, Err(CapacityError(())));
+    /// assert_eq!(vector.len(), 2);
+    /// ```
+    pub fn try_extend_from_slice(
+        &mut self,
+        other: &[T],
+    ) -> Result<(), CapacityError<()>>
+    where
+        T: Clone,
+    {
+        if self.remaining_capacity() < other.len() {
+            return Err(CapacityError(()));
+        }
+
+        for item in other {
+            unsafe {
+                // we've already made sure there's enough room. Pushing one
+                // at a time means the length is always correct if clone()
+                // panics
+                self.push_unchecked(item.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy every item in a slice to the end of the vector with a single
+    /// `memcpy`.
+    ///
+    /// # Panics
+    ///
+    /// The vector must have enough space for every item in the slice (see
+    /// [`ArrayVec::try_extend_from_slice_copy()`]).
+    pub fn extend_from_slice_copy(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        if let Err(e) = self.try_extend_from_slice_copy(other) {
+            panic!("Extend failed: {}", e);
+        }
+    }
+
+    /// Try to copy every item in a slice to the end of the vector.
+    ///
+    /// This is the [`Copy`] counterpart to
+    /// [`ArrayVec::try_extend_from_slice()`], moving all the items with a
+    /// single `memcpy` instead of cloning them one at a time. Nothing is
+    /// added if there isn't enough room for the entire slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u8, 4> = ArrayVec::new();
+    ///
+    /// vector.try_extend_from_slice_copy(b"abc").unwrap();
+    /// assert_eq!(vector.as_slice(), b"abc");
+    ///
+    /// let got = vector.try_extend_from_slice_copy(b"de");
+    /// assert_eq!(got, Err(CapacityError(())));
+    /// assert_eq!(vector.as_slice(), b"abc");
+    /// ```
+    pub fn try_extend_from_slice_copy(
+        &mut self,
+        other: &[T],
+    ) -> Result<(), CapacityError<()>>
+    where
+        T: Copy,
+    {
+        let len = self.len();
+
+        if self.remaining_capacity() < other.len() {
+            return Err(CapacityError(()));
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                self.as_mut_ptr().add(len),
+                other.len(),
+            );
+            self.set_len(len + other.len());
+        }
+
+        Ok(())
+    }
+
+    /// Clone the items in `range` and add them to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]) and the vector
+    /// must have enough space for the cloned items (see
+    /// [`ArrayVec::try_extend_from_within()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 6> = ArrayVec::new();
+    /// vector.extend(1..=3);
+    ///
+    /// vector.extend_from_within(1..);
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 2, 3]);
+    /// ```
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = checked_range!("extend_from_within", range, self.len());
+
+        if let Err(e) = self.extend_from_range(range) {
+            panic!("Extend failed: {}", e);
+        }
+    }
+
+    /// Try to clone the items in `range` and add them to the end of the
+    /// vector.
+    ///
+    /// Nothing is added if there isn't enough room for every item in the
+    /// range. If a call to `clone()` panics, the items cloned so far are
+    /// kept.
+    ///
+    /// [`Copy`] items can be added with a single `memcpy` using
+    /// [`ArrayVec::try_extend_from_within_copy()`].
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u32, 4> = ArrayVec::new();
+    /// vector.extend(1..=3);
+    ///
+    /// assert_eq!(vector.try_extend_from_within(..2), Err(CapacityError(())));
+    /// assert_eq!(vector.try_extend_from_within(..1), Ok(()));
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 1]);
+    /// ```
+    pub fn try_extend_from_within<R>(
+        &mut self,
+        range: R,
+    ) -> Result<(), CapacityError<()>>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = checked_range!("try_extend_from_within", range, self.len());
+        self.extend_from_range(range)
+    }
+
+    /// Copy the items in `range` to the end of the vector with a single
+    /// `memcpy`.
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]) and the vector
+    /// must have enough space for the copied items (see
+    /// [`ArrayVec::try_extend_from_within_copy()`]).
+    pub fn extend_from_within_copy<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        if let Err(e) = self.try_extend_from_within_copy(range) {
+            panic!("Extend failed: {}", e);
+        }
+    }
+
+    /// Try to copy the items in `range` to the end of the vector.
+    ///
+    /// This is the [`Copy`] counterpart to
+    /// [`ArrayVec::try_extend_from_within()`], moving all the items with a
+    /// single `memcpy` instead of cloning them one at a time. Nothing is
+    /// added if there isn't enough room for every item in the range.
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u8, 5> = ArrayVec::new();
+    /// vector.extend_from_slice_copy(b"abc");
+    ///
+    /// assert_eq!(vector.try_extend_from_within_copy(..), Err(CapacityError(())));
+    /// vector.try_extend_from_within_copy(1..).unwrap();
+    /// assert_eq!(vector.as_slice(), b"abcbc");
+    /// ```
+    pub fn try_extend_from_within_copy<R>(
+        &mut self,
+        range: R,
+    ) -> Result<(), CapacityError<()>>
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        let len = self.len();
+        let range = checked_range!("try_extend_from_within_copy", range, len);
+
+        if self.remaining_capacity() < range.len() {
+            return Err(CapacityError(()));
+        }
+
+        unsafe {
+            // the destination starts at len, so it can't overlap the range
+            let base = self.as_mut_ptr();
+            ptr::copy_nonoverlapping(
+                base.add(range.start),
+                base.add(len),
+                range.len(),
+            );
+            self.set_len(len + range.len());
+        }
+
+        Ok(())
+    }
+
+    /// The guts of [`ArrayVec::try_extend_from_within()`], for a range which
+    /// has already been checked.
+    fn extend_from_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<(), CapacityError<()>>
+    where
+        T: Clone,
+    {
+        if self.remaining_capacity() < range.len() {
+            return Err(CapacityError(()));
+        }
+
+        for index in range {
+            let item = self[index].clone();
+
+            unsafe {
+                // we've already made sure there's enough room
+                self.push_unchecked(item);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Convert a full vector into the array it's backed by, returning the
+    /// vector if it isn't full.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    ///
+    /// let vector = vector.into_inner().unwrap_err();
+    /// assert_eq!(vector.len(), 2);
+    ///
+    /// let mut vector = vector;
+    /// vector.push(3);
+    /// assert_eq!(vector.into_inner(), Ok([1, 2, 3]));
+    /// ```
+    pub fn into_inner(self) -> Result<[T; N], ArrayVec<T, N, L>> {
+        if self.is_full() {
+            Ok(unsafe { self.into_inner_unchecked() })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Convert the vector into the array it's backed by without checking
+    /// whether it's full.
+    ///
+    /// # Safety
+    ///
+    /// The vector must be full, otherwise the array will contain
+    /// uninitialized items.
+    pub unsafe fn into_inner_unchecked(self) -> [T; N] {
+        debug_assert!(self.is_full());
+
+        // ownership of the items is transferred to the array, so make sure
+        // our destructor doesn't run
+        let this = mem::ManuallyDrop::new(self);
+
+        // Note: Safe because [MaybeUninit<T>; N] has the same layout as
+        // [T; N] and every item has been initialized
+        ptr::read(this.as_ptr() as *const [T; N])
+    }
+
+    /// Move the items into a vector with a capacity of `M`.
+    ///
+    /// The capacity is checked at compile time, so this only compiles when
+    /// `M` is at least `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let vector = ArrayVec::from([1, 2, 3]);
+    ///
+    /// let bigger: ArrayVec<u32, 8> = vector.into_capacity();
+    ///
+    /// assert_eq!(bigger.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(bigger.capacity(), 8);
+    /// ```
+    ///
+    /// Trying to move into a smaller vector is a compile error.
+    ///
+    /// ```rust,compile_fail
+    /// use const_arrayvec::ArrayVec;
+    /// let vector: ArrayVec<u32, 8> = ArrayVec::new();
+    ///
+    /// let smaller: ArrayVec<u32, 4> = vector.into_capacity();
+    /// ```
+    pub fn into_capacity<const M: usize>(self) -> ArrayVec<T, M, L> {
+        let () = AssertFits::<N, M>::OK;
+
+        unsafe { self.into_capacity_unchecked() }
+    }
+
+    /// Try to move the items into a vector with a capacity of `M`, returning
+    /// the original vector if there are too many items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 16> = ArrayVec::new();
+    /// vector.extend(1..=3);
+    ///
+    /// let compact: ArrayVec<u32, 3> = vector.try_into_capacity().unwrap();
+    /// assert_eq!(compact.as_slice(), &[1, 2, 3]);
+    ///
+    /// let too_small = compact.try_into_capacity::<2>();
+    /// assert_eq!(too_small.unwrap_err().as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn try_into_capacity<const M: usize>(
+        self,
+    ) -> Result<ArrayVec<T, M, L>, ArrayVec<T, N, L>> {
+        if self.len() <= M {
+            Ok(unsafe { self.into_capacity_unchecked() })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Move the items into a vector with a capacity of `M` without checking
+    /// whether they fit.
+    ///
+    /// # Safety
+    ///
+    /// The vector's length must not be greater than `M`.
+    pub unsafe fn into_capacity_unchecked<const M: usize>(
+        self,
+    ) -> ArrayVec<T, M, L> {
+        debug_assert!(self.len() <= M);
+
+        // ownership of the items is transferred to the new vector, so make
+        // sure our destructor doesn't run
+        let this = mem::ManuallyDrop::new(self);
+        let mut other = ArrayVec::new();
+
+        ptr::copy_nonoverlapping(this.as_ptr(), other.as_mut_ptr(), this.len());
+        other.set_len(this.len());
+
+        other
+    }
+
+    /// Split the vector in two at `at`, returning a new vector containing
+    /// the items from `at` onwards and leaving `[0, at)` in place.
+    ///
+    /// # Panics
+    ///
+    /// `at` must not be greater than the vector's length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5]);
+    ///
+    /// let tail = vector.split_off(2);
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2]);
+    /// assert_eq!(tail.as_slice(), &[3, 4, 5]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> ArrayVec<T, N, L> {
+        let len = self.len();
+
+        if at > len {
+            out_of_bounds!("split_off", at, len);
+        }
+
+        let mut other = ArrayVec::new();
+        let count = len - at;
+
+        unsafe {
+            // ownership of the tail is transferred to the other vector
+            self.set_len(at);
+            ptr::copy_nonoverlapping(
+                self.as_ptr().add(at),
+                other.as_mut_ptr(),
+                count,
+            );
+            other.set_len(count);
+        }
+
+        other
+    }
+
+    /// Move every item from `other` to the end of this vector, leaving
+    /// `other` empty.
+    ///
+    /// # Panics
+    ///
+    /// The vector must have enough space for all of `other`'s items (see
+    /// [`ArrayVec::try_append()`]).
+    pub fn append<const M: usize, L2: LengthType>(
+        &mut self,
+        other: &mut ArrayVec<T, M, L2>,
+    ) {
+        if let Err(e) = self.try_append(other) {
+            panic!("Append failed: {}", e);
+        }
+    }
+
+    /// Try to move every item from `other` to the end of this vector,
+    /// leaving `other` empty.
+    ///
+    /// If there isn't enough room for all of `other`'s items, both vectors
+    /// are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u32, 4> = ArrayVec::new();
+    /// vector.push(1);
+    /// let mut other = ArrayVec::from([2, 3]);
+    ///
+    /// vector.try_append(&mut other).unwrap();
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// assert!(other.is_empty());
+    ///
+    /// let mut too_big = ArrayVec::from([4, 5]);
+    /// assert_eq!(vector.try_append(&mut too_big), Err(CapacityError(())));
+    /// assert_eq!(too_big.len(), 2);
+    /// ```
+    pub fn try_append<const M: usize, L2: LengthType>(
+        &mut self,
+        other: &mut ArrayVec<T, M, L2>,
+    ) -> Result<(), CapacityError<()>> {
+        let self_len = self.len();
+        let other_len = other.len();
+
+        if self.remaining_capacity() < other_len {
+            return Err(CapacityError(()));
+        }
+
+        unsafe {
+            // ownership of the items is transferred to this vector
+            other.set_len(0);
+            ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                self.as_mut_ptr().add(self_len),
+                other_len,
+            );
+            self.set_len(self_len + other_len);
+        }
+
+        Ok(())
+    }
+
+    /// Resize the vector in-place so its length is `new_length`, either
+    /// filling the new slots with clones of `value` or truncating.
+    ///
+    /// # Panics
+    ///
+    /// `new_length` must not be greater than the vector's capacity (see
+    /// [`ArrayVec::try_resize()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 5> = ArrayVec::new();
+    /// vector.push(1);
+    ///
+    /// vector.resize(4, 7);
+    /// assert_eq!(vector.as_slice(), &[1, 7, 7, 7]);
+    ///
+    /// vector.resize(2, 0);
+    /// assert_eq!(vector.as_slice(), &[1, 7]);
+    /// ```
+    pub fn resize(&mut self, new_length: usize, value: T)
+    where
+        T: Clone,
+    {
+        if let Err(e) = self.try_resize(new_length, value) {
+            panic!("Resize failed: {}", e);
+        }
+    }
+
+    /// Try to resize the vector in-place so its length is `new_length`,
+    /// either filling the new slots with clones of `value` or truncating.
+    ///
+    /// If `new_length` is greater than the vector's capacity, the vector is
+    /// left unchanged and `value` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+    ///
+    /// assert_eq!(vector.try_resize(4, 7), Err(CapacityError(7)));
+    /// assert!(vector.is_empty());
+    /// ```
+    pub fn try_resize(
+        &mut self,
+        new_length: usize,
+        value: T,
+    ) -> Result<(), CapacityError<T>>
+    where
+        T: Clone,
+    {
+        let len = self.len();
+
+        if new_length > self.capacity() {
+            return Err(CapacityError(value));
+        }
+
+        if new_length > len {
+            for _ in len + 1..new_length {
+                unsafe {
+                    // we've already checked the capacity
+                    self.push_unchecked(value.clone());
+                }
+            }
+
+            // the last slot can take the original instead of a clone
+            unsafe {
+                self.push_unchecked(value);
+            }
+        } else {
+            self.truncate(new_length);
+        }
+
+        Ok(())
+    }
+
+    /// Resize the vector in-place so its length is `new_length`, either
+    /// filling the new slots with values returned by `f` or truncating.
+    ///
+    /// # Panics
+    ///
+    /// `new_length` must not be greater than the vector's capacity (see
+    /// [`ArrayVec::try_resize_with()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 5> = ArrayVec::new();
+    /// let mut next = 0;
+    ///
+    /// vector.resize_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_length: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if let Err(e) = self.try_resize_with(new_length, f) {
+            panic!("Resize failed: {}", e);
+        }
+    }
+
+    /// Try to resize the vector in-place so its length is `new_length`,
+    /// either filling the new slots with values returned by `f` or
+    /// truncating.
+    ///
+    /// If `new_length` is greater than the vector's capacity, the vector is
+    /// left unchanged and `f` is never called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+    ///
+    /// assert_eq!(vector.try_resize_with(4, || 7), Err(CapacityError(())));
+    /// assert!(vector.is_empty());
+    /// ```
+    pub fn try_resize_with<F>(
+        &mut self,
+        new_length: usize,
+        mut f: F,
+    ) -> Result<(), CapacityError<()>>
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+
+        if new_length > self.capacity() {
+            return Err(CapacityError(()));
+        }
+
+        if new_length > len {
+            for _ in len..new_length {
+                unsafe {
+                    // we've already checked the capacity
+                    self.push_unchecked(f());
+                }
+            }
+        } else {
+            self.truncate(new_length);
+        }
+
+        Ok(())
+    }
+
+    /// Try to add every item from an iterator to the end of the vector.
+    ///
+    /// If the vector fills up, the item which didn't fit is returned along
+    /// with the rest of the iterator. Any items added before that point are
+    /// left in the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+    ///
+    /// let CapacityError((rejected, rest)) =
+    ///     vector.try_extend(1..=5).unwrap_err();
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(rejected, 4);
+    /// assert_eq!(rest.collect::<Vec<_>>(), vec![5]);
+    /// ```
+    pub fn try_extend<I>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<(T, I::IntoIter)>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        for item in iter.by_ref() {
+            if let Err(CapacityError(item)) = self.try_push(item) {
+                return Err(CapacityError((item, iter)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add items from an iterator to the end of the vector, stopping once the
+    /// vector is full.
+    ///
+    /// No items are taken from the iterator after the vector fills up.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+    /// let mut numbers = 1..=5;
+    ///
+    /// vector.extend_truncating(&mut numbers);
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(numbers.next(), Some(4));
+    /// ```
+    pub fn extend_truncating<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let remaining = self.remaining_capacity();
+
+        for item in iter.into_iter().take(remaining) {
+            unsafe {
+                // we never take more than the remaining capacity
+                self.push_unchecked(item);
+            }
+        }
+    }
+
+    /// Try to create a new [`ArrayVec`] from the items in an iterator.
+    ///
+    /// If the iterator contains more than `N` items, the first item which
+    /// didn't fit is returned along with the rest of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let vector: ArrayVec<u32, 5> = ArrayVec::try_from_iter(1..4).unwrap();
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    ///
+    /// let got = ArrayVec::<u32, 2>::try_from_iter(1..4);
+    /// assert_eq!(got.unwrap_err().0 .0, 3);
+    /// ```
+    pub fn try_from_iter<I>(
+        iter: I,
+    ) -> Result<Self, CapacityError<(T, I::IntoIter)>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vector = ArrayVec::new();
+        vector.try_extend(iter)?;
+        Ok(vector)
+    }
+
+    /// Create a new [`ArrayVec`] from the first `N` items in an iterator,
+    /// ignoring the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let vector: ArrayVec<u32, 3> = ArrayVec::from_iter_truncating(1..10);
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn from_iter_truncating<I>(iter: I) -> ArrayVec<T, N, L>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vector = ArrayVec::new();
+        vector.extend_truncating(iter);
+        vector
+    }
+
+    /// Remove the items in a range from the vector, returning an iterator
+    /// which yields them.
+    ///
+    /// Any items which haven't been yielded will be dropped when the
+    /// [`Drain`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// The range start must not be greater than the range end, and the end
+    /// must not be greater than the vector's length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5, 6]);
+    ///
+    /// let drained: Vec<_> = vector.drain(4..).collect();
+    /// assert_eq!(drained, vec![5, 6]);
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 4]);
+    ///
+    /// vector.drain(..=1);
+    /// assert_eq!(vector.as_slice(), &[3, 4]);
+    ///
+    /// vector.drain(..);
+    /// assert!(vector.is_empty());
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N, L>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = checked_range!("drain", range, self.len());
+        Drain::with_range(self, range)
+    }
+
+    /// Create an iterator which removes and yields every item in `range`
+    /// that matches `predicate`, leaving the rest in the vector in their
+    /// original order.
+    ///
+    /// The predicate gets a mutable reference to each item, so items which
+    /// are kept can also be updated. Use `..` to scan the entire vector.
+    ///
+    /// Items are only looked at as the iterator is advanced. If the
+    /// [`ExtractIf`] is dropped part-way, any items which haven't been
+    /// visited are kept.
+    ///
+    /// # Panics
+    ///
+    /// The range start must not be greater than the range end, and the end
+    /// must not be greater than the vector's length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// let evens: Vec<_> = vector.extract_if(.., |x| *x % 2 == 0).collect();
+    ///
+    /// assert_eq!(evens, vec![2, 4, 6, 8]);
+    /// assert_eq!(vector.as_slice(), &[1, 3, 5, 7]);
+    /// ```
+    pub fn extract_if<R, F>(
+        &mut self,
+        range: R,
+        predicate: F,
+    ) -> ExtractIf<'_, T, F, N, L>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&mut T) -> bool,
+    {
+        let range = checked_range!("extract_if", range, self.len());
+        ExtractIf::with_range(self, range, predicate)
+    }
+
+    /// Replace the items in `range` with the contents of `replace_with`,
+    /// returning an iterator which yields the removed items.
+    ///
+    /// The replacement items are inserted when the [`Splice`] is dropped,
+    /// and any removed items which weren't yielded are dropped at the same
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]), and the lower
+    /// bound of `replace_with`'s [`Iterator::size_hint()`] must fit in the
+    /// vector once the range is removed (see [`ArrayVec::try_splice()`]).
+    ///
+    /// Any replacement items which still don't fit are dropped along with
+    /// the [`Splice`] instead of being inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector: ArrayVec<u32, 6> = ArrayVec::new();
+    /// vector.extend(1..=5);
+    ///
+    /// let removed: Vec<_> = vector.splice(1..3, vec![7, 8, 9]).collect();
+    ///
+    /// assert_eq!(removed, vec![2, 3]);
+    /// assert_eq!(vector.as_slice(), &[1, 7, 8, 9, 4, 5]);
+    /// ```
+    pub fn splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Splice<'_, I::IntoIter, N, L>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = checked_range!("splice", range, self.len());
+        let replace_with = replace_with.into_iter();
+
+        // check up front, the Splice can't panic when it's dropped because
+        // we might already be unwinding
+        let (lower_bound, _) = replace_with.size_hint();
+        if self.len() - range.len() + lower_bound > N {
+            panic!("Splice failed: {}", CapacityError(()));
+        }
+
+        Splice::new(Drain::with_range(self, range), replace_with)
+    }
+
+    /// Try to replace the items in `range` with the contents of
+    /// `replace_with`, returning an iterator which yields the removed items.
+    ///
+    /// If the replacement items won't fit, the vector is left unchanged and
+    /// `replace_with` is returned.
+    ///
+    /// The replacement items are collected before the vector is touched, so
+    /// unlike [`ArrayVec::splice()`] no replacement items are ever dropped
+    /// for lack of space.
+    ///
+    /// # Panics
+    ///
+    /// The range must be valid (see [`ArrayVec::drain()`]).
+    ///
+    /// This will also panic if `replace_with` yields more items than its
+    /// [`ExactSizeIterator::len()`] promised and they won't fit. The vector
+    /// is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4]);
+    ///
+    /// assert!(vector.try_splice(1..2, vec![5, 6]).is_err());
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 4]);
+    ///
+    /// vector.try_splice(1..3, vec![5, 6]).unwrap();
+    /// assert_eq!(vector.as_slice(), &[1, 5, 6, 4]);
+    /// ```
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, IntoIter<T, N>, N, L>, CapacityError<I::IntoIter>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let range = checked_range!("try_splice", range, self.len());
+        let replace_with = replace_with.into_iter();
+        let kept = self.len() - range.len();
+
+        if kept + replace_with.len() > N {
+            return Err(CapacityError(replace_with));
+        }
+
+        // we can't trust len(), so make sure everything fits before the
+        // vector gets modified
+        let mut buffer = ArrayVec::<T, N>::new();
+
+        for item in replace_with {
+            if kept + buffer.len() == N {
+                panic!(
+                    "ArrayVec::try_splice(): the replacement iterator yielded \
+                     more items than its len()"
+                );
+            }
+            buffer.push(item);
+        }
+
+        Ok(Splice::new(
+            Drain::with_range(self, range),
+            buffer.into_iter(),
+        ))
+    }
+
+    /// If the range is valid, remove its items from the vector and return an
+    /// iterator which yields them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, RangeError};
+    /// let mut vector = ArrayVec::from([1, 2, 3, 4]);
+    ///
+    /// let err = vector.try_drain(2..100).unwrap_err();
+    /// assert_eq!(err, RangeError::OutOfBounds { end: 100, len: 4 });
+    /// let err = vector.try_drain(3..1).unwrap_err();
+    /// assert_eq!(err, RangeError::StartAfterEnd { start: 3, end: 1 });
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3, 4]);
+    ///
+    /// let drained: Vec<_> = vector.try_drain(1..3).unwrap().collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(vector.as_slice(), &[1, 4]);
+    /// ```
+    pub fn try_drain<R>(
+        &mut self,
+        range: R,
+    ) -> Result<Drain<'_, T, N, L>, RangeError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = resolve_range(&range, len);
+
+        if end > len {
+            Err(RangeError::OutOfBounds { end, len })
+        } else if start > end {
+            Err(RangeError::StartAfterEnd { start, end })
+        } else {
+            Ok(Drain::with_range(self, start..end))
+        }
+    }
+}
+
+impl<T, const N: usize, L: LengthType> Deref for ArrayVec<T, N, L> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+}
+
+impl<T, const N: usize, L: LengthType> DerefMut for ArrayVec<T, N, L> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, const N: usize, L: LengthType> Drop for ArrayVec<T, N, L> {
+    /// Makes sure all items are cleaned up once you're done with the
+    /// [`ArrayVec`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::{mem, sync::atomic::{AtomicUsize, Ordering}};
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// // create a dummy type which increments a number when dropped
+    ///
+    /// struct OnDropped<'a>(&'a AtomicUsize);
+    ///
+    /// impl<'a> Drop for OnDropped<'a> {
+    ///   fn drop(&mut self) { self.0.fetch_add(1, Ordering::Relaxed); }
+    /// }
+    ///
+    /// // create our vector
+    /// let mut vector: ArrayVec<OnDropped<'_>, 5> = ArrayVec::new();
+    ///
+    /// // then set up our counter
+    /// let counter = AtomicUsize::new(0);
+    ///
+    /// // and add a couple `OnDropped`'s to the vector
+    /// vector.push(OnDropped(&counter));
+    /// vector.push(OnDropped(&counter));
+    /// vector.push(OnDropped(&counter));
+    ///
+    /// // the vector is still live so our counter shouldn't have changed
+    /// assert_eq!(counter.load(Ordering::Relaxed), 0);
+    ///
+    /// // explicitly drop the vector
+    /// mem::drop(vector);
+    ///
+    /// // and the counter should have updated
+    /// assert_eq!(counter.load(Ordering::Relaxed), 3);
+    /// ```
+    #[inline]
+    fn drop(&mut self) {
+        // Makes sure the destructors for all items are run.
+        self.clear();
+    }
+}
+
+/// The same as the stable destructor, except we promise the compiler that
+/// items are only ever dropped, letting them hold references which don't
+/// outlive the vector itself.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, const N: usize, L: LengthType> Drop
+    for ArrayVec<T, N, L>
+{
+    #[inline]
+    fn drop(&mut self) {
+        // Makes sure the destructors for all items are run.
+        self.clear();
+    }
+}
+
+impl<T, const N: usize, L: LengthType> AsRef<[T]> for ArrayVec<T, N, L> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const N: usize, L: LengthType> AsMut<[T]> for ArrayVec<T, N, L> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+
+impl<T: Debug, const N: usize, L: LengthType> Debug for ArrayVec<T, N, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T, const N: usize, const M: usize, L, L2> PartialEq<ArrayVec<T, M, L2>>
+    for ArrayVec<T, N, L>
+where
+    T: PartialEq,
+    L: LengthType,
+    L2: LengthType,
+{
+    #[inline]
+    fn eq(&self, other: &ArrayVec<T, M, L2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize, L: LengthType> PartialEq<[T]>
+    for ArrayVec<T, N, L>
+{
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: Eq, const N: usize, L: LengthType> Eq for ArrayVec<T, N, L> {}
+
+impl<T: PartialOrd, const N: usize, L: LengthType> PartialOrd
+    for ArrayVec<T, N, L>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize, L: LengthType> Ord for ArrayVec<T, N, L> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize, L: LengthType> Hash for ArrayVec<T, N, L> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) { self.as_slice().hash(hasher); }
+}
+
+impl<T, const N: usize, L: LengthType> Default for ArrayVec<T, N, L> {
+    #[inline]
+    fn default() -> Self { ArrayVec::new() }
+}
+
+impl<Ix, T, const N: usize, L: LengthType> Index<Ix> for ArrayVec<T, N, L>
+where
+    [T]: Index<Ix>,
 {
     type Output = <[T] as Index<Ix>>::Output;
 
@@ -701,7 +2213,7 @@ where
     fn index(&self, ix: Ix) -> &Self::Output { self.as_slice().index(ix) }
 }
 
-impl<Ix, T, const N: usize> IndexMut<Ix> for ArrayVec<T, { N }>
+impl<Ix, T, const N: usize, L: LengthType> IndexMut<Ix> for ArrayVec<T, N, L>
 where
     [T]: IndexMut<Ix>,
 {
@@ -711,9 +2223,9 @@ where
     }
 }
 
-impl<T: Clone, const N: usize> Clone for ArrayVec<T, { N }> {
-    fn clone(&self) -> ArrayVec<T, { N }> {
-        let mut other: ArrayVec<T, { N }> = ArrayVec::new();
+impl<T: Clone, const N: usize, L: LengthType> Clone for ArrayVec<T, N, L> {
+    fn clone(&self) -> ArrayVec<T, N, L> {
+        let mut other: ArrayVec<T, N, L> = ArrayVec::new();
 
         for item in self.as_slice() {
             unsafe {
@@ -726,28 +2238,127 @@ impl<T: Clone, const N: usize> Clone for ArrayVec<T, { N }> {
     }
 }
 
-impl<T, const N: usize> From<[T; N]> for ArrayVec<T, { N }> {
-    fn from(other: [T; N]) -> ArrayVec<T, { N }> {
-        let mut vec = ArrayVec::<T, { N }>::new();
-
-        unsafe {
-            // Copy the items from the array directly to the backing buffer
+// Note: this is only implemented for the default length type so
+// `ArrayVec::from([...])` doesn't need a type annotation. Use
+// `ArrayVec::from_array()` for the others.
+impl<T, const N: usize> From<[T; N]> for ArrayVec<T, N> {
+    #[inline]
+    fn from(other: [T; N]) -> ArrayVec<T, N> { ArrayVec::from_array(other) }
+}
 
-            // Note: Safe because a [T; N] is identical to [MaybeUninit<T>; N]
-            ptr::copy_nonoverlapping(
-                other.as_ptr(),
-                vec.as_mut_ptr(),
-                other.len(),
-            );
-            // ownership has been transferred to the backing buffer, make sure
-            // the original array's destructors aren't called prematurely
-            mem::forget(other);
-            // the memory has now been initialized so it's safe to set the
-            // length
-            vec.set_len(N);
+impl<T, const N: usize, L: LengthType> Extend<T> for ArrayVec<T, N, L> {
+    /// Add every item from an iterator to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// The vector must have enough space for all the items (see
+    /// [`ArrayVec::try_extend()`] for a fallible version).
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if let Err(e) = self.try_extend(iter) {
+            panic!("Extend failed: {}", e);
         }
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize, L: LengthType> Extend<&'a T>
+    for ArrayVec<T, N, L>
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const N: usize, L: LengthType> FromIterator<T> for ArrayVec<T, N, L> {
+    /// Collect the items from an iterator into an [`ArrayVec`].
+    ///
+    /// # Panics
+    ///
+    /// The iterator must not yield more than `N` items (see
+    /// [`ArrayVec::try_from_iter()`] for a fallible version).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::ArrayVec;
+    ///
+    /// let vector: ArrayVec<u32, 5> = (1..4).collect();
+    ///
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> ArrayVec<T, N, L> {
+        let mut vector = ArrayVec::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, const N: usize, L: LengthType> IntoIterator for ArrayVec<T, N, L> {
+    type IntoIter = IntoIter<T, N, L>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter::new(self) }
+}
+
+impl<'a, T, const N: usize, L: LengthType> IntoIterator
+    for &'a ArrayVec<T, N, L>
+{
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const N: usize, L: LengthType> IntoIterator
+    for &'a mut ArrayVec<T, N, L>
+{
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<'a, T: Clone, const N: usize, L: LengthType> TryFrom<&'a [T]>
+    for ArrayVec<T, N, L>
+{
+    type Error = CapacityError<&'a [T]>;
+
+    /// Clone the items in a slice into a new [`ArrayVec`], returning the
+    /// slice if it's too long.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, CapacityError};
+    /// use core::convert::TryFrom;
+    ///
+    /// let vector = ArrayVec::<u32, 4>::try_from(&[1, 2, 3][..]).unwrap();
+    /// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    ///
+    /// let got = ArrayVec::<u32, 2>::try_from(&[1, 2, 3][..]);
+    /// assert_eq!(got, Err(CapacityError(&[1, 2, 3][..])));
+    /// ```
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        let mut vector = ArrayVec::new();
+        vector
+            .try_extend_from_slice(slice)
+            .map_err(|_| CapacityError(slice))?;
+        Ok(vector)
+    }
+}
 
-        vec
+impl<T, const N: usize, L: LengthType> TryFrom<ArrayVec<T, N, L>> for [T; N] {
+    type Error = ArrayVec<T, N, L>;
+
+    /// Convert a full vector into the array it's backed by (see
+    /// [`ArrayVec::into_inner()`]).
+    #[inline]
+    fn try_from(
+        vector: ArrayVec<T, N, L>,
+    ) -> Result<[T; N], ArrayVec<T, N, L>> {
+        vector.into_inner()
     }
 }
 
@@ -762,6 +2373,38 @@ impl<T> Display for CapacityError<T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: Debug> std::error::Error for CapacityError<T> {}
+
+/// The error returned when a range isn't valid for a vector.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RangeError {
+    /// The range starts after it ends.
+    StartAfterEnd { start: usize, end: usize },
+    /// The range goes past the end of the vector.
+    OutOfBounds { end: usize, len: usize },
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            RangeError::StartAfterEnd { start, end } => write!(
+                f,
+                "range start {} is greater than range end {}",
+                start, end
+            ),
+            RangeError::OutOfBounds { end, len } => write!(
+                f,
+                "index {} is out of bounds in vector of length {}",
+                end, len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
 #[cfg(test)]
 mod tests {
     use super::ArrayVec;
@@ -805,4 +2448,331 @@ mod tests {
         assert_eq!(vector.swap_remove(0), 4);
         assert_eq!(vector.len(), 0);
     }
+
+    #[test]
+    #[should_panic(expected = "Extend failed")]
+    fn collect_panics_when_iterator_is_too_long() {
+        let _: ArrayVec<u8, 2> = (0..3).collect();
+    }
+
+    #[test]
+    fn extend_leaves_the_vector_unchanged_when_iterator_is_empty() {
+        let mut vector = ArrayVec::from([1, 2]);
+
+        vector.extend(core::iter::empty::<i32>());
+        vector.extend(&[] as &[i32]);
+
+        assert_eq!(vector.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ArrayVec::drain(): index 100 is out of bounds")]
+    fn drain_past_the_end() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4]);
+
+        vector.drain(2..100);
+    }
+
+    #[test]
+    #[should_panic(expected = "is greater than range end")]
+    fn drain_with_start_after_end() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4]);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        vector.drain(3..1);
+    }
+
+    #[test]
+    fn try_drain_with_overflowing_bounds() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4]);
+
+        assert_eq!(
+            vector.try_drain(..=usize::MAX).unwrap_err(),
+            super::RangeError::OutOfBounds {
+                end: usize::MAX,
+                len: 4
+            }
+        );
+        assert!(vector.try_drain(..=3).is_ok());
+        assert!(vector.is_empty());
+    }
+
+    #[test]
+    fn retain_and_dedup_are_panic_safe() {
+        extern crate std;
+
+        use crate::test_helpers::OnDropped;
+        use core::{
+            cell::Cell,
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+        use std::panic::{self, AssertUnwindSafe};
+
+        let counter = AtomicUsize::new(0);
+        let mut vector: ArrayVec<(u32, OnDropped<'_>), 6> = ArrayVec::new();
+        for i in &[1, 2, 2, 3, 4, 4] {
+            vector.push((*i, OnDropped(&counter)));
+        }
+
+        // retain drops the 2s, then panics when it sees the 3
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector.retain(|item| match item.0 {
+                2 => false,
+                3 => panic!(),
+                _ => true,
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(counter.load(Ordering::Relaxed), 2);
+        let values: ArrayVec<u32, 6> = vector.iter().map(|x| x.0).collect();
+        assert_eq!(values.as_slice(), &[1, 3, 4, 4]);
+
+        drop(vector);
+        assert_eq!(counter.load(Ordering::Relaxed), 6);
+
+        // dedup removes the duplicate 5, then panics on its next comparison
+        let mut vector: ArrayVec<(u32, OnDropped<'_>), 6> = ArrayVec::new();
+        for i in &[5, 5, 6, 6, 7, 7] {
+            vector.push((*i, OnDropped(&counter)));
+        }
+        let calls = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector.dedup_by(|a, b| {
+                calls.set(calls.get() + 1);
+                if calls.get() == 3 {
+                    panic!();
+                }
+                a.0 == b.0
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(counter.load(Ordering::Relaxed), 7);
+        let values: ArrayVec<u32, 6> = vector.iter().map(|x| x.0).collect();
+        assert_eq!(values.as_slice(), &[5, 6, 6, 7, 7]);
+
+        drop(vector);
+        assert_eq!(counter.load(Ordering::Relaxed), 12);
+    }
+
+    #[test]
+    fn extend_from_slice_keeps_clones_if_clone_panics() {
+        extern crate std;
+
+        use crate::test_helpers::PanicOnThirdClone;
+        use core::cell::Cell;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let clones = Cell::new(0);
+        let source = [
+            PanicOnThirdClone(1, &clones),
+            PanicOnThirdClone(2, &clones),
+            PanicOnThirdClone(3, &clones),
+            PanicOnThirdClone(4, &clones),
+        ];
+        let mut vector: ArrayVec<PanicOnThirdClone<'_>, 5> = ArrayVec::new();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector.extend_from_slice(&source)
+        }));
+
+        assert!(result.is_err());
+        let values: ArrayVec<u32, 5> = vector.iter().map(|x| x.0).collect();
+        assert_eq!(values.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn try_extend_from_within_keeps_clones_if_clone_panics() {
+        extern crate std;
+
+        use crate::test_helpers::PanicOnThirdClone;
+        use core::cell::Cell;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let clones = Cell::new(0);
+        let mut vector: ArrayVec<PanicOnThirdClone<'_>, 8> = ArrayVec::new();
+        vector.extend((1..=4).map(|i| PanicOnThirdClone(i, &clones)));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector.try_extend_from_within(..)
+        }));
+
+        assert!(result.is_err());
+        let values: ArrayVec<u32, 8> = vector.iter().map(|x| x.0).collect();
+        assert_eq!(values.as_slice(), &[1, 2, 3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn split_off_at_either_end() {
+        let mut vector = ArrayVec::from([1, 2, 3]);
+
+        let tail = vector.split_off(3);
+        assert!(tail.is_empty());
+        assert_eq!(vector.as_slice(), &[1, 2, 3]);
+
+        let tail = vector.split_off(0);
+        assert!(vector.is_empty());
+        assert_eq!(tail.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn try_append_leaves_both_vectors_unchanged_on_overflow() {
+        let mut vector: ArrayVec<u32, 4> = ArrayVec::new();
+        vector.extend(1..=3);
+        let mut other = ArrayVec::from([4, 5]);
+
+        assert_eq!(
+            vector.try_append(&mut other),
+            Err(super::CapacityError(()))
+        );
+
+        assert_eq!(vector.as_slice(), &[1, 2, 3]);
+        assert_eq!(other.as_slice(), &[4, 5]);
+    }
+
+    #[test]
+    fn append_from_a_vector_with_a_different_capacity_and_length() {
+        let mut vector: ArrayVec<u32, 8> = ArrayVec::new();
+        vector.push(1);
+        let mut other: ArrayVec<u32, 3, u8> = ArrayVec::new();
+        other.extend(2..=4);
+
+        vector.append(&mut other);
+
+        assert_eq!(vector.as_slice(), &[1, 2, 3, 4]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn split_off_and_append_move_items_without_dropping_them() {
+        use crate::test_helpers::OnDropped;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = AtomicUsize::new(0);
+        let mut vector: ArrayVec<OnDropped<'_>, 5> =
+            (0..4).map(|_| OnDropped(&counter)).collect();
+
+        let mut tail = vector.split_off(1);
+        assert_eq!((vector.len(), tail.len()), (1, 3));
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        vector.append(&mut tail);
+        assert_eq!((vector.len(), tail.len()), (4, 0));
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+        drop(tail);
+        assert_eq!(counter.load(Ordering::Relaxed), 0);
+        drop(vector);
+        assert_eq!(counter.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn try_from_fn_drops_the_items_built_before_an_error() {
+        use crate::test_helpers::OnDropped;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = AtomicUsize::new(0);
+
+        let got: Result<ArrayVec<OnDropped<'_>, 5>, usize> =
+            ArrayVec::try_from_fn(5, |i| {
+                if i == 3 {
+                    Err(i)
+                } else {
+                    Ok(OnDropped(&counter))
+                }
+            });
+
+        assert_eq!(got.err(), Some(3));
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn from_fn_drops_the_items_built_before_a_panic() {
+        extern crate std;
+
+        use crate::test_helpers::OnDropped;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use std::panic::{self, AssertUnwindSafe};
+
+        let counter = AtomicUsize::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            ArrayVec::<OnDropped<'_>, 5>::from_fn(5, |i| {
+                if i == 2 {
+                    panic!();
+                }
+                OnDropped(&counter)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(counter.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn from_array_len_drops_the_excess_items() {
+        use crate::test_helpers::OnDropped;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = AtomicUsize::new(0);
+        let array = [
+            OnDropped(&counter),
+            OnDropped(&counter),
+            OnDropped(&counter),
+            OnDropped(&counter),
+        ];
+
+        let vector: ArrayVec<_, 4> = ArrayVec::from_array_len(array, 1);
+        assert_eq!(vector.len(), 1);
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+
+        drop(vector);
+        assert_eq!(counter.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "is greater than the capacity")]
+    fn from_array_len_with_a_length_past_the_capacity() {
+        let _: ArrayVec<u32, 3> = ArrayVec::from_array_len([1, 2, 3], 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "is greater than the capacity")]
+    fn from_fn_with_a_length_past_the_capacity() {
+        let _: ArrayVec<usize, 3> = ArrayVec::from_fn(4, |i| i);
+    }
+
+    #[test]
+    fn const_fns_can_build_a_static() {
+        static VECTOR: ArrayVec<u8, 4> = {
+            let mut vector = ArrayVec::new();
+            vector.push(1);
+            vector.push(2);
+            vector.push(3);
+            let _ = vector.pop();
+            vector
+        };
+        static SHORT: ArrayVec<u8, 4, u8> = ArrayVec::from_array([1, 2, 3, 4]);
+        const LEN: usize = SHORT.len();
+
+        assert_eq!(VECTOR.as_slice(), &[1, 2]);
+        assert_eq!(SHORT.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(LEN, 4);
+    }
+
+    #[test]
+    fn convert_a_full_vector_back_into_an_array() {
+        extern crate std;
+
+        use core::convert::TryFrom;
+        use std::{borrow::ToOwned, string::String};
+
+        let vector = ArrayVec::from(["a".to_owned(), "b".to_owned()]);
+        let array = <[String; 2]>::try_from(vector).unwrap();
+        assert_eq!(array, ["a".to_owned(), "b".to_owned()]);
+
+        let mut vector: ArrayVec<String, 2> = ArrayVec::new();
+        vector.push("a".to_owned());
+        let vector = <[String; 2]>::try_from(vector).unwrap_err();
+        assert_eq!(vector.as_slice(), &["a".to_owned()]);
+    }
 }
@@ -0,0 +1,88 @@
+/// Create an [`ArrayVec`] containing the arguments.
+///
+/// Like `vec!`, there are two forms. A list of items:
+///
+/// ```rust
+/// use const_arrayvec::{array_vec, ArrayVec};
+///
+/// let vector: ArrayVec<u32, 8> = array_vec![1, 2, 3];
+///
+/// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+/// assert_eq!(vector.capacity(), 8);
+/// ```
+///
+/// Or an item and a length, where the item is cloned to fill the vector. The
+/// length must be a constant.
+///
+/// ```rust
+/// use const_arrayvec::{array_vec, ArrayVec};
+///
+/// let vector: ArrayVec<String, 4> = array_vec!["x".to_owned(); 2];
+///
+/// assert_eq!(vector.as_slice(), &["x", "x"]);
+/// ```
+///
+/// The capacity is normally inferred from context, but it can also be given
+/// explicitly by adding a `cap = ...;` prefix.
+///
+/// ```rust
+/// use const_arrayvec::array_vec;
+///
+/// let vector = array_vec![cap = 8; 1, 2, 3];
+/// assert_eq!(vector.capacity(), 8);
+///
+/// let zeroes = array_vec![cap = 8; 0_u8; 5];
+/// assert_eq!(zeroes.as_slice(), &[0, 0, 0, 0, 0]);
+/// ```
+///
+/// Trying to create a vector with more items than it can hold is a compile
+/// error.
+///
+/// ```rust,compile_fail
+/// use const_arrayvec::array_vec;
+///
+/// let vector = array_vec![cap = 2; 1, 2, 3];
+/// ```
+///
+/// [`ArrayVec`]: crate::ArrayVec
+#[macro_export]
+macro_rules! array_vec {
+    (cap = $cap:expr; $item:expr; $len:expr) => {{
+        let vector: $crate::ArrayVec<_, { $cap }> =
+            $crate::array_vec![$item; $len];
+        vector
+    }};
+    (cap = $cap:expr; $($item:expr),* $(,)?) => {{
+        let vector: $crate::ArrayVec<_, { $cap }> =
+            $crate::array_vec![$($item),*];
+        vector
+    }};
+    ($item:expr; $len:expr) => {
+        $crate::__private::Repeat::<{ $len }>::fill($item)
+    };
+    ($($item:expr),* $(,)?) => {
+        $crate::ArrayVec::from([$($item),*]).into_capacity()
+    };
+}
+
+/// Implementation details for [`array_vec!`]. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{ArrayVec, AssertFits};
+
+    /// Fill a vector with `L` clones of an item.
+    ///
+    /// This is a type so `L` can be given explicitly while the capacity is
+    /// inferred.
+    pub struct Repeat<const L: usize>;
+
+    impl<const L: usize> Repeat<L> {
+        pub fn fill<T: Clone, const N: usize>(item: T) -> ArrayVec<T, N> {
+            let () = AssertFits::<L, N>::OK;
+
+            let mut vector = ArrayVec::new();
+            vector.resize(L, item);
+            vector
+        }
+    }
+}
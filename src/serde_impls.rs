@@ -0,0 +1,167 @@
+//! [`serde`] integration, enabled with the `serde` feature.
+
+use crate::{ArrayString, ArrayVec, LengthType};
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serializes the vector as a sequence.
+impl<T: Serialize, const N: usize, L: LengthType> Serialize
+    for ArrayVec<T, N, L>
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Deserializes the vector from a sequence.
+///
+/// Items are written directly into the vector's backing buffer, and a
+/// sequence with more than `N` items is rejected with an error instead of
+/// being truncated.
+impl<'de, T, const N: usize, L> Deserialize<'de> for ArrayVec<T, N, L>
+where
+    T: Deserialize<'de>,
+    L: LengthType,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+    }
+}
+
+struct ArrayVecVisitor<T, const N: usize, L>(PhantomData<(T, L)>);
+
+impl<'de, T, const N: usize, L> Visitor<'de> for ArrayVecVisitor<T, N, L>
+where
+    T: Deserialize<'de>,
+    L: LengthType,
+{
+    type Value = ArrayVec<T, N, L>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence with at most {} items", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error> {
+        // bail early when we already know it won't fit
+        if let Some(len) = seq.size_hint() {
+            if len > N {
+                return Err(de::Error::invalid_length(len, &self));
+            }
+        }
+
+        let mut vector = ArrayVec::new();
+
+        while let Some(item) = seq.next_element()? {
+            if vector.try_push(item).is_err() {
+                let len = seq.size_hint().map_or(N + 1, |rest| N + 1 + rest);
+                return Err(de::Error::invalid_length(len, &self));
+            }
+        }
+
+        Ok(vector)
+    }
+}
+
+/// Serializes the string as a `str`.
+impl<const N: usize> Serialize for ArrayString<N> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// Deserializes the string from a `str`, failing if it is longer than `N`
+/// bytes.
+impl<'de, const N: usize> Deserialize<'de> for ArrayString<N> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ArrayStringVisitor)
+    }
+}
+
+struct ArrayStringVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ArrayStringVisitor<N> {
+    type Value = ArrayString<N>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a string with at most {} bytes", N)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        let mut string = ArrayString::new();
+
+        match string.try_push_str(s) {
+            Ok(()) => Ok(string),
+            Err(_) => Err(E::invalid_length(s.len(), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn round_trip_as_a_sequence() {
+        let vector = ArrayVec::<u16, 4>::from_iter_truncating([1, 2, 3]);
+
+        assert_tokens(
+            &vector,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U16(1),
+                Token::U16(2),
+                Token::U16(3),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn too_many_items_is_an_error() {
+        // the length is known up front, so we fail before reading any items
+        assert_de_tokens_error::<ArrayVec<u8, 2>>(
+            &[Token::Seq { len: Some(3) }],
+            "invalid length 3, expected a sequence with at most 2 items",
+        );
+        assert_de_tokens_error::<ArrayVec<u8, 2>>(
+            &[
+                Token::Seq { len: None },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+            ],
+            "invalid length 3, expected a sequence with at most 2 items",
+        );
+    }
+
+    #[test]
+    fn strings_round_trip() {
+        let string: ArrayString<8> = "hello".parse().unwrap();
+
+        assert_tokens(&string, &[Token::Str("hello")]);
+        assert_de_tokens_error::<ArrayString<4>>(
+            &[Token::Str("hello")],
+            "invalid length 5, expected a string with at most 4 bytes",
+        );
+    }
+}
@@ -0,0 +1,598 @@
+//! A vector which stores a handful of items inline before spilling onto the
+//! heap, enabled with the `alloc` feature.
+
+use crate::{ArrayVec, CapacityError};
+use alloc::vec::{self, Vec};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    mem,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+/// A vector which stores up to `N` items inline, like an [`ArrayVec`], and
+/// transparently moves them to a heap-allocated [`Vec`] when it runs out of
+/// room.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::SmallVec;
+///
+/// let mut vector: SmallVec<u32, 2> = SmallVec::new();
+///
+/// vector.push(1);
+/// vector.push(2);
+/// assert!(!vector.spilled());
+///
+/// vector.push(3);
+/// assert!(vector.spilled());
+/// assert_eq!(vector.as_slice(), &[1, 2, 3]);
+///
+/// vector.pop();
+/// assert!(vector.shrink_to_inline());
+/// assert!(!vector.spilled());
+/// ```
+pub struct SmallVec<T, const N: usize> {
+    data: Data<T, N>,
+}
+
+enum Data<T, const N: usize> {
+    Inline(ArrayVec<T, N>),
+    Heap(Vec<T>),
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    /// Create a new, empty [`SmallVec`].
+    #[inline]
+    pub const fn new() -> SmallVec<T, N> {
+        SmallVec {
+            data: Data::Inline(ArrayVec::new()),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.as_slice().len() }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// The number of items the vector can hold without (re)allocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match self.data {
+            Data::Inline(_) => N,
+            Data::Heap(ref vector) => vector.capacity(),
+        }
+    }
+
+    /// The number of items which can be stored inline.
+    #[inline]
+    pub const fn inline_capacity(&self) -> usize { N }
+
+    /// Have the items been moved onto the heap?
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        match self.data {
+            Data::Inline(_) => false,
+            Data::Heap(_) => true,
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match self.data {
+            Data::Inline(ref vector) => vector.as_slice(),
+            Data::Heap(ref vector) => vector.as_slice(),
+        }
+    }
+
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        match self.data {
+            Data::Inline(ref mut vector) => vector.as_slice_mut(),
+            Data::Heap(ref mut vector) => vector.as_mut_slice(),
+        }
+    }
+
+    /// Make sure there is room for at least `additional` more items,
+    /// spilling onto the heap if they won't fit inline.
+    pub fn reserve(&mut self, additional: usize) {
+        match self.data {
+            Data::Inline(ref vector)
+                if additional <= vector.remaining_capacity() => {},
+            Data::Inline(_) => self.spill(additional),
+            Data::Heap(ref mut vector) => vector.reserve(additional),
+        }
+    }
+
+    /// Add an item to the end of the vector, spilling onto the heap if
+    /// there's no more room inline.
+    pub fn push(&mut self, item: T) {
+        self.reserve(1);
+
+        match self.data {
+            Data::Inline(ref mut vector) => unsafe {
+                // we just reserved room for it
+                vector.push_unchecked(item);
+            },
+            Data::Heap(ref mut vector) => vector.push(item),
+        }
+    }
+
+    /// Remove an item from the end of the vector.
+    pub fn pop(&mut self) -> Option<T> {
+        match self.data {
+            Data::Inline(ref mut vector) => vector.pop(),
+            Data::Heap(ref mut vector) => vector.pop(),
+        }
+    }
+
+    /// Insert an item at `index`, shifting everything after it to the
+    /// right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the vector's length.
+    pub fn insert(&mut self, index: usize, item: T) {
+        let len = self.len();
+        if index > len {
+            panic!(
+                "SmallVec::insert(): index {} is out of bounds in vector of length {}",
+                index, len
+            );
+        }
+
+        self.reserve(1);
+
+        match self.data {
+            Data::Inline(ref mut vector) => vector.insert(index, item),
+            Data::Heap(ref mut vector) => vector.insert(index, item),
+        }
+    }
+
+    /// Remove the item at `index`, shifting everything after it to the
+    /// left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!(
+                "SmallVec::remove(): index {} is out of bounds in vector of length {}",
+                index, len
+            );
+        }
+
+        match self.data {
+            Data::Inline(ref mut vector) => vector.remove(index),
+            Data::Heap(ref mut vector) => vector.remove(index),
+        }
+    }
+
+    /// Remove the item at `index`, replacing it with the last item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!(
+                "SmallVec::swap_remove(): index {} is out of bounds in vector of length {}",
+                index, len
+            );
+        }
+
+        match self.data {
+            Data::Inline(ref mut vector) => vector.swap_remove(index),
+            Data::Heap(ref mut vector) => vector.swap_remove(index),
+        }
+    }
+
+    /// Shorten the vector to `new_length` items, dropping the rest.
+    ///
+    /// This has no effect on the vector's capacity.
+    pub fn truncate(&mut self, new_length: usize) {
+        match self.data {
+            Data::Inline(ref mut vector) => vector.truncate(new_length),
+            Data::Heap(ref mut vector) => vector.truncate(new_length),
+        }
+    }
+
+    /// Remove all items from the vector.
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0); }
+
+    /// Only keep the items which match a predicate.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self.data {
+            Data::Inline(ref mut vector) => vector.retain(f),
+            Data::Heap(ref mut vector) => vector.retain(f),
+        }
+    }
+
+    /// Clone and append every item in a slice.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+
+        match self.data {
+            Data::Inline(ref mut vector) => {
+                if vector.try_extend_from_slice(other).is_err() {
+                    unreachable!("We reserved enough room");
+                }
+            },
+            Data::Heap(ref mut vector) => vector.extend_from_slice(other),
+        }
+    }
+
+    /// Move the items back inline if there is enough room, freeing the heap
+    /// allocation.
+    ///
+    /// Returns `true` if the items are now stored inline.
+    pub fn shrink_to_inline(&mut self) -> bool {
+        let vector = match self.data {
+            Data::Inline(_) => return true,
+            Data::Heap(ref mut vector) if vector.len() <= N => {
+                mem::take(vector)
+            },
+            Data::Heap(_) => return false,
+        };
+
+        match ArrayVec::try_from(vector) {
+            Ok(vector) => self.data = Data::Inline(vector),
+            Err(_) => unreachable!("We've checked the items fit"),
+        }
+        true
+    }
+
+    /// Convert into a [`Vec`], reusing the heap allocation if the items have
+    /// already spilled.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.data {
+            Data::Inline(vector) => vector.into_vec(),
+            Data::Heap(vector) => vector,
+        }
+    }
+
+    /// Move the inline items onto the heap, making room for at least
+    /// `additional` more.
+    fn spill(&mut self, additional: usize) {
+        if let Data::Inline(ref mut inline) = self.data {
+            let capacity = usize::max(
+                N.saturating_mul(2),
+                inline.len().saturating_add(additional),
+            );
+            let mut vector = Vec::with_capacity(capacity);
+            vector.extend(inline.drain(..));
+
+            self.data = Data::Heap(vector);
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+
+impl<T, const N: usize> DerefMut for SmallVec<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target { self.as_slice_mut() }
+}
+
+impl<T, const N: usize> AsRef<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const N: usize> AsMut<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+
+impl<T: Debug, const N: usize> Debug for SmallVec<T, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallVec<T, N> {
+    fn clone(&self) -> SmallVec<T, N> {
+        let data = match self.data {
+            Data::Inline(ref vector) => Data::Inline(vector.clone()),
+            Data::Heap(ref vector) => Data::Heap(vector.clone()),
+        };
+
+        SmallVec { data }
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<SmallVec<T, M>>
+    for SmallVec<T, N>
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallVec<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SmallVec<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for SmallVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) { self.as_slice().hash(hasher); }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    #[inline]
+    fn default() -> Self { SmallVec::new() }
+}
+
+impl<T, const N: usize> Extend<T> for SmallVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SmallVec<T, N> {
+        let mut vector = SmallVec::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, const N: usize> From<ArrayVec<T, N>> for SmallVec<T, N> {
+    /// Wrap an [`ArrayVec`], storing its items inline.
+    #[inline]
+    fn from(vector: ArrayVec<T, N>) -> SmallVec<T, N> {
+        SmallVec {
+            data: Data::Inline(vector),
+        }
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for SmallVec<T, N> {
+    /// Wrap a [`Vec`], reusing its heap allocation.
+    ///
+    /// Use [`SmallVec::shrink_to_inline()`] to move short vectors inline.
+    #[inline]
+    fn from(vector: Vec<T>) -> SmallVec<T, N> {
+        SmallVec {
+            data: Data::Heap(vector),
+        }
+    }
+}
+
+impl<T, const N: usize> From<SmallVec<T, N>> for Vec<T> {
+    #[inline]
+    fn from(vector: SmallVec<T, N>) -> Vec<T> { vector.into_vec() }
+}
+
+impl<T, const N: usize> TryFrom<SmallVec<T, N>> for ArrayVec<T, N> {
+    type Error = CapacityError<SmallVec<T, N>>;
+
+    /// Move the items into an [`ArrayVec`], failing if there are more than
+    /// `N` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_arrayvec::{ArrayVec, SmallVec};
+    /// use std::convert::TryFrom;
+    ///
+    /// let vector: SmallVec<u32, 4> = vec![1, 2, 3].into();
+    /// assert!(vector.spilled());
+    ///
+    /// let inline = ArrayVec::try_from(vector).unwrap();
+    /// assert_eq!(inline.as_slice(), &[1, 2, 3]);
+    /// ```
+    fn try_from(mut vector: SmallVec<T, N>) -> Result<Self, Self::Error> {
+        if !vector.shrink_to_inline() {
+            return Err(CapacityError(vector));
+        }
+
+        match vector.data {
+            Data::Inline(vector) => Ok(vector),
+            Data::Heap(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallVec<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.data {
+            Data::Inline(vector) => Inner::Inline(vector.into_iter()),
+            Data::Heap(vector) => Inner::Heap(vector.into_iter()),
+        };
+
+        IntoIter { inner }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVec<T, N> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVec<T, N> {
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+/// An iterator which moves items out of a [`SmallVec`].
+///
+/// This `struct` is created by the `into_iter()` method on [`SmallVec`]
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Clone)]
+pub struct IntoIter<T, const N: usize> {
+    inner: Inner<T, N>,
+}
+
+#[derive(Clone)]
+enum Inner<T, const N: usize> {
+    Inline(crate::IntoIter<T, N>),
+    Heap(vec::IntoIter<T>),
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// The remaining items, as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        match self.inner {
+            Inner::Inline(ref iter) => iter.as_slice(),
+            Inner::Heap(ref iter) => iter.as_slice(),
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Inner::Inline(ref mut iter) => iter.next(),
+            Inner::Heap(ref mut iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.as_slice().len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Inner::Inline(ref mut iter) => iter.next_back(),
+            Inner::Heap(ref mut iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T: Debug, const N: usize> Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn spill_when_inserting_into_a_full_vector() {
+        let mut vector: SmallVec<u32, 3> = (1..=3).collect();
+        assert!(!vector.spilled());
+
+        vector.insert(0, 0);
+
+        assert!(vector.spilled());
+        assert!(vector.capacity() >= 4);
+        assert_eq!(vector.as_slice(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn extend_spills_once() {
+        let mut vector: SmallVec<u32, 2> = SmallVec::new();
+
+        vector.extend_from_slice(&[1, 2, 3, 4, 5]);
+        vector.extend(6..8);
+
+        assert!(vector.spilled());
+        assert_eq!(vector.into_vec(), vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn only_shrink_when_the_items_fit() {
+        let mut vector: SmallVec<u32, 2> = vec![1, 2, 3].into();
+
+        assert!(!vector.shrink_to_inline());
+        assert!(vector.spilled());
+
+        vector.truncate(2);
+        assert!(vector.shrink_to_inline());
+        assert!(!vector.spilled());
+        assert_eq!(vector.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn convert_to_and_from_array_vec() {
+        let vector = SmallVec::from(ArrayVec::from([1, 2, 3]));
+        assert!(!vector.spilled());
+
+        let mut vector: SmallVec<u32, 3> = vector.into_iter().rev().collect();
+        vector.push(0);
+
+        let err = ArrayVec::try_from(vector).unwrap_err();
+        assert_eq!(err.0.as_slice(), &[3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn into_iter_from_the_heap() {
+        let vector: SmallVec<u32, 1> = (1..=3).collect();
+
+        let mut iter = vector.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.as_slice(), &[2]);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{Drain, LengthType};
+use core::{
+    iter::{DoubleEndedIterator, FusedIterator},
+    mem,
+};
+
+/// An iterator which replaces a range of items in an [`ArrayVec`] with the
+/// contents of another iterator, yielding the removed items.
+///
+/// This `struct` is created by [`ArrayVec::splice()`] and
+/// [`ArrayVec::try_splice()`].
+///
+/// The replacement items are only inserted once the [`Splice`] is dropped.
+/// Any which don't fit are dropped instead.
+///
+/// [`ArrayVec`]: crate::ArrayVec
+/// [`ArrayVec::splice()`]: crate::ArrayVec::splice
+/// [`ArrayVec::try_splice()`]: crate::ArrayVec::try_splice
+#[derive(Debug)]
+pub struct Splice<'a, I, const N: usize, L: LengthType = usize>
+where
+    I: Iterator,
+{
+    drain: Drain<'a, I::Item, N, L>,
+    replace_with: I,
+}
+
+impl<'a, I, const N: usize, L: LengthType> Splice<'a, I, N, L>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(
+        drain: Drain<'a, I::Item, N, L>,
+        replace_with: I,
+    ) -> Self {
+        Splice {
+            drain,
+            replace_with,
+        }
+    }
+}
+
+impl<'a, I, const N: usize, L: LengthType> Iterator for Splice<'a, I, N, L>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.drain.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.drain.size_hint() }
+}
+
+impl<'a, I, const N: usize, L: LengthType> DoubleEndedIterator
+    for Splice<'a, I, N, L>
+where
+    I: Iterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.drain.next_back() }
+}
+
+impl<'a, I, const N: usize, L: LengthType> ExactSizeIterator
+    for Splice<'a, I, N, L>
+where
+    I: Iterator,
+{
+}
+
+impl<'a, I, const N: usize, L: LengthType> FusedIterator for Splice<'a, I, N, L> where
+    I: Iterator
+{
+}
+
+impl<'a, I, const N: usize, L: LengthType> Drop for Splice<'a, I, N, L>
+where
+    I: Iterator,
+{
+    fn drop(&mut self) {
+        // get rid of any removed items the caller didn't want
+        self.drain.by_ref().for_each(mem::drop);
+
+        // Note: we can't panic if the replacement items overflow because we
+        // might already be unwinding. ArrayVec::splice() checked the
+        // size_hint(), so just get rid of the item which didn't fit and let
+        // the rest be dropped along with the iterator
+        if let Some(item) = self.drain.fill(&mut self.replace_with) {
+            mem::drop(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ArrayVec;
+
+    #[test]
+    fn replace_with_fewer_items() {
+        let mut vector = ArrayVec::from([1, 2, 3, 4, 5]);
+
+        let removed: ArrayVec<_, 5> = vector.splice(1..4, Some(42)).collect();
+
+        assert_eq!(removed.as_slice(), &[2, 3, 4]);
+        assert_eq!(vector.as_slice(), &[1, 42, 5]);
+    }
+
+    #[test]
+    fn replace_with_more_items_than_were_removed() {
+        let mut vector: ArrayVec<u32, 8> = ArrayVec::new();
+        vector.extend(1..=4);
+
+        vector.splice(1..2, 10..15);
+
+        assert_eq!(vector.as_slice(), &[1, 10, 11, 12, 13, 14, 3, 4]);
+    }
+
+    #[test]
+    fn replace_at_the_end() {
+        let mut vector: ArrayVec<u32, 8> = ArrayVec::new();
+        vector.extend(1..=4);
+
+        vector.splice(4.., 5..=8);
+
+        assert_eq!(vector.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Splice failed")]
+    fn overflowing_the_capacity() {
+        let mut vector = ArrayVec::from([1, 2, 3]);
+
+        // the panic comes from splice() itself, not the Splice's destructor
+        let splice = vector.splice(..1, 0..3);
+        core::mem::forget(splice);
+    }
+
+    #[test]
+    fn excess_items_from_a_wrong_size_hint_are_dropped() {
+        use crate::test_helpers::OnDropped;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = AtomicUsize::new(0);
+        let mut vector: ArrayVec<OnDropped<'_>, 3> =
+            (0..3).map(|_| OnDropped(&counter)).collect();
+        let mut replacements: ArrayVec<OnDropped<'_>, 3> =
+            (0..3).map(|_| OnDropped(&counter)).collect();
+
+        // filter() has a lower bound of 0, so splice() can't tell that only
+        // one of these will fit
+        vector.splice(..1, replacements.drain(..).filter(|_| true));
+
+        assert_eq!(vector.len(), 3);
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+    }
+
+    /// An iterator which claims to be empty.
+    #[derive(Debug)]
+    struct LyingLen<I>(I);
+
+    impl<I: Iterator> Iterator for LyingLen<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> { self.0.next() }
+
+        fn size_hint(&self) -> (usize, Option<usize>) { (0, Some(0)) }
+    }
+
+    impl<I: Iterator> ExactSizeIterator for LyingLen<I> {}
+
+    #[test]
+    fn try_splice_with_a_wrong_len_that_still_fits() {
+        let mut vector: ArrayVec<u32, 4> = ArrayVec::new();
+        vector.extend(1..=3);
+
+        vector.try_splice(1..2, LyingLen(5..7)).unwrap();
+
+        assert_eq!(vector.as_slice(), &[1, 5, 6, 3]);
+    }
+
+    #[test]
+    fn try_splice_with_a_wrong_len_leaves_the_vector_unchanged() {
+        extern crate std;
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut vector = ArrayVec::from([1, 2, 3]);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vector.try_splice(..1, LyingLen(4..7)).unwrap();
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(vector.as_slice(), &[1, 2, 3]);
+    }
+}
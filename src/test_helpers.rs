@@ -0,0 +1,35 @@
+//! Fixtures shared by the unit tests.
+
+use core::{
+    cell::Cell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A dummy type which increments a counter when it's dropped.
+#[derive(Debug)]
+pub(crate) struct OnDropped<'a>(pub(crate) &'a AtomicUsize);
+
+impl<'a> Drop for OnDropped<'a> {
+    fn drop(&mut self) { self.0.fetch_add(1, Ordering::Relaxed); }
+}
+
+/// A dummy type which panics the third time any item sharing its counter is
+/// cloned.
+#[derive(Debug)]
+pub(crate) struct PanicOnThirdClone<'a>(
+    pub(crate) u32,
+    pub(crate) &'a Cell<usize>,
+);
+
+impl<'a> Clone for PanicOnThirdClone<'a> {
+    fn clone(&self) -> Self {
+        let clones = self.1.get();
+        self.1.set(clones + 1);
+
+        if clones == 2 {
+            panic!();
+        }
+
+        PanicOnThirdClone(self.0, self.1)
+    }
+}